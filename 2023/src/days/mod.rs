@@ -1 +0,0 @@
-automod::dir!(pub "src/days");
@@ -0,0 +1,303 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, Data, DeriveInput, Fields, GenericArgument, Ident, LitStr, PathArguments, Type};
+
+// A format pattern, such as `{name} = ({left}, {right})`, made of literals
+// around the placeholders of the fields.
+struct Pattern {
+  prefix: String,
+  // Each placeholder, with the literal that follows it.
+  placeholders: Vec<(String, String)>,
+  span: Span,
+}
+
+impl Pattern {
+  fn parse(fmt: &LitStr) -> syn::Result<Pattern> {
+    let text = fmt.value();
+    let error = |message: &str| syn::Error::new(fmt.span(), message);
+
+    let mut pattern = Pattern {
+      prefix: String::new(),
+      placeholders: vec![],
+      span: fmt.span(),
+    };
+
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+      let literal = match pattern.placeholders.last_mut() {
+        Some((_, literal)) => literal,
+        None => &mut pattern.prefix,
+      };
+
+      match c {
+        '{' if chars.peek() == Some(&'{') => {
+          chars.next();
+          literal.push('{');
+        }
+
+        '}' if chars.peek() == Some(&'}') => {
+          chars.next();
+          literal.push('}');
+        }
+
+        '{' => {
+          if pattern.placeholders.last().is_some_and(|(_, literal)| literal.is_empty()) {
+            return Err(error("placeholders must be separated by some text, so that they can be told apart"));
+          }
+
+          let name = chars.by_ref().take_while(|c| *c != '}').collect::<String>();
+
+          if name.trim().is_empty() {
+            return Err(error("placeholders must name a field, as in `{name}`"));
+          }
+
+          pattern.placeholders.push((name.trim().to_string(), String::new()));
+        }
+
+        '}' => return Err(error("unmatched `}`, use `}}` for a literal one")),
+        c => literal.push(c),
+      }
+    }
+
+    Ok(pattern)
+  }
+
+  // Splits the token into the tokens of the placeholders, bound to the given
+  // variables, in order.
+  fn split(&self, variables: &[Ident]) -> TokenStream {
+    let prefix = &self.prefix;
+
+    let mut code = match (self.placeholders.is_empty(), prefix.is_empty()) {
+      (true, _) => quote! {
+        if token != #prefix {
+          return Err(line.error(token, concat!("`", #prefix, "`")));
+        }
+      },
+
+      (false, true) => quote!(let rest = token;),
+      (false, false) => quote!(let rest = line.strip_prefix(token, #prefix)?;),
+    };
+
+    for (index, ((_, literal), variable)) in self.placeholders.iter().zip(variables).enumerate() {
+      let last = index == self.placeholders.len() - 1;
+
+      code.extend(match (last, literal.is_empty()) {
+        (false, _) => quote!(let (#variable, rest) = line.split_once(rest, #literal)?;),
+        (true, true) => quote!(let #variable = rest;),
+        (true, false) => quote! {
+          let #variable = rest.strip_suffix(#literal).ok_or_else(|| line.error(rest, concat!("`", #literal, "`")))?;
+        },
+      });
+    }
+
+    code
+  }
+}
+
+// How a field is read from its token: lists are split on each of their
+// separators in turn, down to their items.
+struct Field {
+  separators: Vec<String>,
+  from_str: bool,
+}
+
+impl Field {
+  fn parse(attributes: &[syn::Attribute]) -> syn::Result<Field> {
+    let mut field = Field { separators: vec![], from_str: false };
+
+    for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident("aoc")) {
+      attribute.parse_nested_meta(|meta| {
+        if meta.path.is_ident("sep") {
+          field.separators.push(meta.value()?.parse::<LitStr>()?.value());
+        } else if meta.path.is_ident("from_str") {
+          field.from_str = true;
+        } else {
+          return Err(meta.error("expected `sep` or `from_str`"));
+        }
+
+        Ok(())
+      })?;
+    }
+
+    Ok(field)
+  }
+
+  fn read(&self, ty: &Type, token: &Ident) -> syn::Result<TokenStream> {
+    self.read_level(ty, quote!(#token), 0)
+  }
+
+  fn read_level(&self, ty: &Type, token: TokenStream, level: usize) -> syn::Result<TokenStream> {
+    let Some(separator) = self.separators.get(level) else {
+      return Ok(match self.from_str {
+        true => quote!(line.parse::<#ty>(#token)),
+        false => quote!(<#ty as crate::util::FromInput>::from_input(line, #token)),
+      });
+    };
+
+    let item = item_type(ty).ok_or_else(|| syn::Error::new(ty.span(), "fields with a separator must be collections, such as `Vec<T>`"))?;
+    let read = self.read_level(item, quote!(item), level + 1)?;
+
+    // Separators made of whitespace only stand for any run of whitespace.
+    let split = match separator.trim().is_empty() {
+      true => quote!(#token.split_whitespace()),
+      false => quote!(#token.split(#separator)),
+    };
+
+    Ok(quote!(#split.map(|item| #read).collect::<crate::error::Result<#ty>>()))
+  }
+}
+
+// The type of the items of a collection, its only generic type.
+fn item_type(ty: &Type) -> Option<&Type> {
+  let Type::Path(path) = ty else { return None };
+  let PathArguments::AngleBracketed(arguments) = &path.path.segments.last()?.arguments else {
+    return None;
+  };
+
+  match arguments.args.iter().collect::<Vec<_>>().as_slice() {
+    [GenericArgument::Type(item)] => Some(item),
+    _ => None,
+  }
+}
+
+fn format(attributes: &[syn::Attribute], span: Span) -> syn::Result<LitStr> {
+  let mut fmt = None;
+
+  for attribute in attributes.iter().filter(|attribute| attribute.path().is_ident("aoc")) {
+    attribute.parse_nested_meta(|meta| match meta.path.is_ident("fmt") {
+      true => {
+        fmt = Some(meta.value()?.parse::<LitStr>()?);
+        Ok(())
+      }
+      false => Err(meta.error("expected `fmt`")),
+    })?;
+  }
+
+  fmt.ok_or_else(|| syn::Error::new(span, "expected a format, as in `#[aoc(fmt = \"{name} = {value}\")]`"))
+}
+
+// Reads the fields from the token, and builds the value at the given path
+// (`Self` or a variant) from them.
+fn construct(path: TokenStream, fields: &Fields, pattern: &Pattern) -> syn::Result<TokenStream> {
+  let names = match fields {
+    Fields::Named(named) => named.named.iter().map(|field| field.ident.as_ref().map(ToString::to_string).unwrap_or_default()).collect::<Vec<_>>(),
+    Fields::Unnamed(unnamed) => (0..unnamed.unnamed.len()).map(|index| index.to_string()).collect(),
+    Fields::Unit => vec![],
+  };
+
+  for (placeholder, _) in &pattern.placeholders {
+    if !names.contains(placeholder) {
+      return Err(syn::Error::new(pattern.span, format!("unknown field `{placeholder}`")));
+    }
+
+    if pattern.placeholders.iter().filter(|(other, _)| other == placeholder).count() > 1 {
+      return Err(syn::Error::new(pattern.span, format!("field `{placeholder}` is used more than once")));
+    }
+  }
+
+  if let Some(missing) = names.iter().find(|name| !pattern.placeholders.iter().any(|(placeholder, _)| placeholder == *name)) {
+    return Err(syn::Error::new(pattern.span, format!("field `{missing}` is missing from the format")));
+  }
+
+  let variables = pattern.placeholders.iter().map(|(name, _)| format_ident!("token_{}", name)).collect::<Vec<_>>();
+  let split = pattern.split(&variables);
+
+  let values = fields
+    .iter()
+    .zip(&names)
+    .map(|(field, name)| {
+      let variable = format_ident!("token_{}", name);
+
+      Field::parse(&field.attrs)?.read(&field.ty, &variable)
+    })
+    .collect::<syn::Result<Vec<_>>>()?;
+
+  let value = match fields {
+    Fields::Named(named) => {
+      let idents = named.named.iter().map(|field| &field.ident);
+
+      quote!(#path { #(#idents: #values?),* })
+    }
+
+    Fields::Unnamed(_) => quote!(#path(#(#values?),*)),
+    Fields::Unit => quote!(#path),
+  };
+
+  Ok(quote! {
+    #split
+    Ok(#value)
+  })
+}
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+  let name = &input.ident;
+
+  let lifetime = match input.generics.lifetimes().collect::<Vec<_>>().as_slice() {
+    [] if input.generics.params.is_empty() => None,
+    [lifetime] if input.generics.params.len() == 1 => Some(lifetime.lifetime.clone()),
+    _ => return Err(syn::Error::new(input.generics.span(), "only types with no generics, or a single lifetime, can be read from inputs")),
+  };
+
+  let body = match &input.data {
+    Data::Struct(data) => construct(quote!(Self), &data.fields, &Pattern::parse(&format(&input.attrs, name.span())?)?)?,
+
+    // Variants are tried in order. When none matches, the error of the only
+    // variant whose leading text matched is reported, if any.
+    Data::Enum(data) => {
+      let mut attempts = vec![];
+      let mut formats = vec![];
+
+      for variant in &data.variants {
+        let fmt = format(&variant.attrs, variant.ident.span())?;
+        let pattern = Pattern::parse(&fmt)?;
+        let ident = &variant.ident;
+        let body = construct(quote!(Self::#ident), &variant.fields, &pattern)?;
+        let prefix = &pattern.prefix;
+
+        let matched = match prefix.is_empty() {
+          true => quote!(),
+          false => quote!(Err(error) if token.starts_with(#prefix) => matched.push(error),),
+        };
+
+        formats.push(format!("`{}`", fmt.value()));
+        attempts.push(quote! {
+          match (|| -> crate::error::Result<Self> { #body })() {
+            Ok(value) => return Ok(value),
+            #matched
+            Err(_) => {}
+          }
+        });
+      }
+
+      let expected = format!("one of {}", formats.join(", "));
+
+      quote! {
+        let mut matched = vec![];
+
+        #(#attempts)*
+
+        match <[crate::error::Error; 1]>::try_from(matched) {
+          Ok([error]) => Err(error),
+          Err(_) => Err(line.error(token, #expected)),
+        }
+      }
+    }
+
+    Data::Union(_) => return Err(syn::Error::new(name.span(), "unions cannot be read from inputs")),
+  };
+
+  let (lifetime, generics) = match lifetime {
+    Some(lifetime) => (lifetime.clone(), quote!(<#lifetime>)),
+    None => (syn::Lifetime::new("'input", Span::call_site()), quote!()),
+  };
+
+  Ok(quote! {
+    impl<#lifetime> crate::util::FromInput<#lifetime> for #name #generics {
+      #[allow(clippy::redundant_closure_call)]
+      fn from_input(line: &crate::util::Line<#lifetime>, token: &#lifetime str) -> crate::error::Result<Self> {
+        #body
+      }
+    }
+  })
+}
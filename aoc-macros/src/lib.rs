@@ -1,9 +1,224 @@
-use std::{env, fs, path::PathBuf};
+use std::{
+  collections::BTreeMap,
+  env, fs,
+  path::{Path, PathBuf},
+};
 
-use proc_macro::{Span, TokenStream};
-use quote::quote;
-use syn::Ident;
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{meta::ParseNestedMeta, parse_macro_input, spanned::Spanned, DeriveInput, ImplItem, Item, ItemFn, LitInt, LitStr, Type};
 
+mod from_input;
+
+// Arguments of `#[aoc::solution(day = 5, part = 2, name = "...")]`.
+#[derive(Default)]
+struct Solution {
+  day: Option<usize>,
+  part: Option<usize>,
+  name: Option<String>,
+}
+
+impl Solution {
+  fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+    if meta.path.is_ident("day") {
+      let day = meta.value()?.parse::<LitInt>()?;
+
+      match day.base10_parse::<usize>()? {
+        day @ 1..=25 => self.day = Some(day),
+        _ => return Err(syn::Error::new(day.span(), "days go from 1 to 25")),
+      }
+    } else if meta.path.is_ident("part") {
+      let part = meta.value()?.parse::<LitInt>()?;
+
+      match part.base10_parse::<usize>()? {
+        part @ 1..=2 => self.part = Some(part),
+        _ => return Err(syn::Error::new(part.span(), "parts are either 1 or 2")),
+      }
+    } else if meta.path.is_ident("name") {
+      self.name = Some(meta.value()?.parse::<LitStr>()?.value());
+    } else {
+      return Err(meta.error("expected one of `day`, `part` or `name`"));
+    }
+
+    Ok(())
+  }
+
+  fn validate(self, span: proc_macro2::Span) -> syn::Result<(usize, usize, Option<String>)> {
+    match (self.day, self.part) {
+      (Some(day), Some(part)) => Ok((day, part, self.name)),
+      _ => Err(syn::Error::new(span, "expected both a `day` and a `part`")),
+    }
+  }
+}
+
+// Marks a part of a `Day` implementation as the solution of a puzzle. The
+// attribute itself only validates its arguments, solutions are collected by
+// `generate_days!`, which reads them from the sources.
+#[proc_macro_attribute]
+pub fn solution(args: TokenStream, item: TokenStream) -> TokenStream {
+  let mut solution = Solution::default();
+  let parser = syn::meta::parser(|meta| solution.parse(meta));
+
+  parse_macro_input!(args with parser);
+
+  let function = parse_macro_input!(item as ItemFn);
+
+  match solution.validate(function.sig.ident.span()) {
+    Ok(_) => quote!(#function).into(),
+    Err(error) => {
+      let error = error.to_compile_error();
+
+      quote!(#error #function).into()
+    }
+  }
+}
+
+// Reads a type from a line of input, or a part of it, following a format
+// pattern where each field has its placeholder:
+//
+//   #[derive(aoc::FromInput)]
+//   #[aoc(fmt = "{name} = ({left}, {right})")]
+//   struct Node { name: String, left: String, right: String }
+//
+// Fields are read with `crate::util::FromInput`, or `FromStr` when marked with
+// `#[aoc(from_str)]`. Collections are split on `#[aoc(sep = ", ")]`, once for
+// each level of nesting. Enums try the format of each of their variants.
+#[proc_macro_derive(FromInput, attributes(aoc))]
+pub fn derive_from_input(input: TokenStream) -> TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+
+  from_input::derive(input).unwrap_or_else(|error| error.to_compile_error()).into()
+}
+
+struct Registration {
+  module: String,
+  solver: String,
+  part: usize,
+  name: Option<String>,
+  location: String,
+}
+
+fn is_solution(attribute: &syn::Attribute) -> bool {
+  attribute.path().segments.last().is_some_and(|segment| segment.ident == "solution")
+}
+
+fn entries(dir: &Path, directories: bool) -> Result<Vec<PathBuf>, String> {
+  let mut entries = fs::read_dir(dir)
+    .map_err(|error| format!("could not read {}: {error}", dir.display()))?
+    .filter_map(|entry| entry.ok())
+    .filter(|entry| entry.file_type().is_ok_and(|kind| if directories { kind.is_dir() } else { kind.is_file() }))
+    .map(|entry| entry.path())
+    .collect::<Vec<_>>();
+
+  entries.sort();
+
+  Ok(entries)
+}
+
+// Collects the parts annotated with `#[aoc::solution]` in the `impl Day`
+// blocks at the top level of the files in `src/days/<year>`.
+fn collect(dir: &Path) -> Result<BTreeMap<(usize, usize), Vec<Registration>>, String> {
+  let mut days: BTreeMap<(usize, usize), Vec<Registration>> = BTreeMap::new();
+
+  for year_dir in entries(dir, true)? {
+    let Some(year) = year_dir
+      .file_name()
+      .and_then(|name| name.to_str())
+      .filter(|name| name.len() == 4)
+      .and_then(|name| name.parse::<usize>().ok())
+    else {
+      continue;
+    };
+
+    for path in entries(&year_dir, false)? {
+      if path.extension().is_none_or(|extension| extension != "rs") || path.file_name().is_some_and(|name| name == "mod.rs") {
+        continue;
+      }
+
+      collect_file(&path, year, &mut days)?;
+    }
+  }
+
+  Ok(days)
+}
+
+fn collect_file(path: &Path, year: usize, days: &mut BTreeMap<(usize, usize), Vec<Registration>>) -> Result<(), String> {
+  let content = fs::read_to_string(path).map_err(|error| format!("could not read {}: {error}", path.display()))?;
+  let file = syn::parse_file(&content).map_err(|error| format!("could not parse {}: {error}", path.display()))?;
+  let display = format!("src/days/{year}/{}", path.file_name().unwrap_or_default().to_string_lossy());
+  let locate = |span: proc_macro2::Span| format!("{display}:{}", span.start().line);
+
+  // Same naming rules as automod, which declares the modules.
+  let mut module = path.file_stem().unwrap_or_default().to_string_lossy().replace('-', "_");
+
+  if module.starts_with(|c: char| c.is_ascii_digit()) {
+    module.insert(0, '_');
+  }
+
+  for item in file.items {
+    let (solver, functions) = match item {
+      Item::Impl(block) if block.trait_.as_ref().is_some_and(|(_, path, _)| path.segments.last().is_some_and(|segment| segment.ident == "Day")) => {
+        let Type::Path(solver) = block.self_ty.as_ref() else { continue };
+        let Some(solver) = solver.path.get_ident() else { continue };
+
+        let functions = block.items.into_iter().filter_map(|item| match item {
+          ImplItem::Fn(function) => Some((function.attrs, function.sig.ident)),
+          _ => None,
+        });
+
+        (solver.to_string(), functions.collect::<Vec<_>>())
+      }
+
+      Item::Fn(function) if function.attrs.iter().any(is_solution) => {
+        return Err(format!(
+          "{}: solutions must be the `part1` or `part2` function of an `impl Day` block",
+          locate(function.sig.ident.span())
+        ));
+      }
+
+      _ => continue,
+    };
+
+    for (attributes, function) in functions {
+      for attribute in attributes.iter().filter(|attribute| is_solution(attribute)) {
+        let mut solution = Solution::default();
+
+        attribute.parse_nested_meta(|meta| solution.parse(meta)).map_err(|error| format!("{}: {error}", locate(error.span())))?;
+
+        let (day, part, name) = solution.validate(function.span()).map_err(|error| format!("{}: {error}", locate(function.span())))?;
+
+        if function != format!("part{part}") {
+          return Err(format!("{}: `{function}` cannot be registered as part {part}, only `part{part}` can", locate(function.span())));
+        }
+
+        let registrations = days.entry((year, day)).or_default();
+        let location = locate(attribute.span());
+
+        if let Some(other) = registrations.iter().find(|other| other.part == part) {
+          return Err(format!("{location}: {year} day {day} part {part} is already registered at {}", other.location));
+        }
+
+        if let Some(other) = registrations.iter().find(|other| other.module != module || other.solver != solver) {
+          return Err(format!("{location}: {year} day {day} is already implemented by `{}` at {}", other.solver, other.location));
+        }
+
+        registrations.push(Registration {
+          module: module.clone(),
+          solver: solver.clone(),
+          part,
+          name,
+          location,
+        });
+      }
+    }
+  }
+
+  Ok(())
+}
+
+// Builds the registry of all the solutions found in `src/days`, ordered by year
+// and day. Each year lives in its own directory, declared as the `y<year>`
+// module of `crate::days`.
 #[proc_macro]
 pub fn generate_days(_input: TokenStream) -> TokenStream {
   let path = "src/days";
@@ -13,17 +228,79 @@ pub fn generate_days(_input: TokenStream) -> TokenStream {
     None => PathBuf::from(path),
   };
 
-  let days = fs::read_dir(dir).unwrap().count();
-  let counter = (1..days).map(|id| Ident::new(&format!("day{:0>2}", id), Span::call_site().into()));
+  // Line numbers are only available from proc-macro2's own implementation,
+  // which is only used while reading the sources.
+  proc_macro2::fallback::force();
+  let days = collect(&dir);
+  proc_macro2::fallback::unforce();
+
+  let days = match days {
+    Ok(days) => days,
+    Err(error) => return quote!(compile_error!(#error)).into(),
+  };
+
+  let solutions = days.into_iter().map(|((year, day), mut registrations)| {
+    let year_module = format_ident!("y{year}");
+    registrations.sort_by_key(|registration| registration.part);
+
+    let module = format_ident!("{}", registrations[0].module);
+    let solver = format_ident!("{}", registrations[0].solver);
+
+    let parts = registrations.iter().map(|registration| {
+      let part = registration.part;
+
+      match &registration.name {
+        Some(name) => quote!((#part, Some(#name))),
+        None => quote!((#part, None)),
+      }
+    });
+
+    quote!(crate::day::Solution::new::<crate::days::#year_module::#module::#solver>(#year, #day, &[#(#parts),*]))
+  });
 
-  let ast = quote! {
-      vec![
-          #((
-              crate::days::#counter::part1 as fn(&str, Option<Box<dyn crate::util::Opt>>) -> i64,
-              crate::days::#counter::part2 as fn(&str, Option<Box<dyn crate::util::Opt>>) -> i64
-          )),*
-      ]
+  quote!(vec![#(#solutions),*]).into()
+}
+
+// Embeds the files of the data directory of each year, the inputs along with
+// the answers and options next to them, as pairs of their path relative to the
+// data directory (e.g. `2023/05.txt`) and their content.
+#[proc_macro]
+pub fn embed_inputs(_input: TokenStream) -> TokenStream {
+  let dir = match env::var_os("CARGO_MANIFEST_DIR") {
+    Some(manifest_dir) => PathBuf::from(manifest_dir).join("data"),
+    None => PathBuf::from("data"),
   };
 
-  ast.into()
+  let years = match dir.is_dir() {
+    true => entries(&dir, true),
+    false => Ok(vec![]),
+  };
+
+  let files = years.and_then(|years| {
+    let years = years.into_iter().filter(|year| {
+      year
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.len() == 4 && name.bytes().all(|byte| byte.is_ascii_digit()))
+    });
+
+    years.map(|year| entries(&year, false)).collect::<Result<Vec<_>, _>>()
+  });
+
+  let files = match files {
+    Ok(files) => files
+      .into_iter()
+      .flatten()
+      .filter(|file| file.extension().is_some_and(|extension| extension == "txt" || extension == "toml")),
+    Err(error) => return quote!(compile_error!(#error)).into(),
+  };
+
+  let files = files.filter_map(|file| {
+    let relative = file.strip_prefix(&dir).ok()?.iter().map(|component| component.to_string_lossy()).collect::<Vec<_>>().join("/");
+    let path = file.to_str()?;
+
+    Some(quote!((#relative, include_str!(#path))))
+  });
+
+  quote!(&[#(#files),*]).into()
 }
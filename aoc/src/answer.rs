@@ -0,0 +1,178 @@
+use std::fmt;
+
+use num::{BigInt, BigUint, ToPrimitive};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+// Integers are normalized into the narrowest variant that can hold them, so
+// that answers compare equal regardless of the type a solver produced them
+// with.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+  Integer(i128),
+  BigInteger(BigInt),
+  Text(String),
+}
+
+macro_rules! from_integer {
+  ($($type:ty),*) => {
+    $(
+      impl From<$type> for Answer {
+        fn from(value: $type) -> Self {
+          Answer::Integer(value as i128)
+        }
+      }
+    )*
+  };
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+  fn from(value: u128) -> Self {
+    match i128::try_from(value) {
+      Ok(value) => Answer::Integer(value),
+      Err(_) => Answer::BigInteger(BigInt::from(value)),
+    }
+  }
+}
+
+impl From<BigInt> for Answer {
+  fn from(value: BigInt) -> Self {
+    match value.to_i128() {
+      Some(value) => Answer::Integer(value),
+      None => Answer::BigInteger(value),
+    }
+  }
+}
+
+impl From<BigUint> for Answer {
+  fn from(value: BigUint) -> Self {
+    Answer::from(BigInt::from(value))
+  }
+}
+
+impl From<String> for Answer {
+  fn from(value: String) -> Self {
+    Answer::Text(value)
+  }
+}
+
+impl From<&str> for Answer {
+  fn from(value: &str) -> Self {
+    Answer::Text(value.to_string())
+  }
+}
+
+impl fmt::Display for Answer {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Answer::Integer(value) => write!(f, "{value}"),
+      Answer::BigInteger(value) => write!(f, "{value}"),
+      Answer::Text(value) => write!(f, "{value}"),
+    }
+  }
+}
+
+impl PartialEq<i128> for Answer {
+  fn eq(&self, other: &i128) -> bool {
+    matches!(self, Answer::Integer(value) if value == other)
+  }
+}
+
+impl PartialEq<&str> for Answer {
+  fn eq(&self, other: &&str) -> bool {
+    matches!(self, Answer::Text(value) if value == other)
+  }
+}
+
+// Integers that do not fit in 64 bits are serialized as strings, since most
+// consumers would silently lose precision on numbers that large.
+impl Serialize for Answer {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match self {
+      Answer::Integer(value) => match (i64::try_from(*value), u64::try_from(*value)) {
+        (Ok(value), _) => serializer.serialize_i64(value),
+        (_, Ok(value)) => serializer.serialize_u64(value),
+        _ => serializer.collect_str(value),
+      },
+
+      Answer::BigInteger(value) => serializer.collect_str(value),
+      Answer::Text(value) => serializer.serialize_str(value),
+    }
+  }
+}
+
+// Strings holding a canonical integer are read back as integers, so that
+// answers survive a round trip through their serialized form.
+impl<'de> Deserialize<'de> for Answer {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct Visitor;
+
+    impl de::Visitor<'_> for Visitor {
+      type Value = Answer;
+
+      fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer or a string")
+      }
+
+      fn visit_i64<E>(self, value: i64) -> Result<Answer, E> {
+        Ok(Answer::from(value))
+      }
+
+      fn visit_u64<E>(self, value: u64) -> Result<Answer, E> {
+        Ok(Answer::from(value))
+      }
+
+      fn visit_str<E>(self, value: &str) -> Result<Answer, E> {
+        match value.parse::<BigInt>() {
+          Ok(integer) if integer.to_string() == value => Ok(Answer::from(integer)),
+          _ => Ok(Answer::from(value)),
+        }
+      }
+    }
+
+    deserializer.deserialize_any(Visitor)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use num::{BigInt, BigUint};
+
+  use super::Answer;
+
+  #[test]
+  fn integers_compare_across_widths() {
+    assert_eq!(Answer::from(42u8), Answer::from(42i64));
+    assert_eq!(Answer::from(42usize), 42);
+    assert_eq!(Answer::from(BigInt::from(42)), Answer::from(42u128));
+  }
+
+  #[test]
+  fn overflowing_integers_are_promoted() {
+    let answer = Answer::from(u128::MAX);
+
+    assert!(matches!(answer, Answer::BigInteger(_)));
+    assert_eq!(answer, Answer::from(BigUint::from(u128::MAX)));
+    assert_eq!(answer.to_string(), u128::MAX.to_string());
+  }
+
+  #[test]
+  fn round_trip() {
+    for answer in [Answer::from(42), Answer::from(u128::MAX), Answer::from(i128::MIN), Answer::from("0042")] {
+      assert_eq!(serde_json::from_str::<Answer>(&serde_json::to_string(&answer).unwrap()).unwrap(), answer);
+    }
+  }
+
+  #[test]
+  fn text() {
+    assert_eq!(Answer::from("EHZLRJ"), "EHZLRJ");
+    assert_ne!(Answer::from("42"), Answer::from(42));
+  }
+}
@@ -0,0 +1,231 @@
+use std::{
+  fs,
+  io::{self, IsTerminal},
+  iter,
+  path::{Path, PathBuf},
+  process,
+  time::Duration,
+};
+
+use itertools::Itertools;
+
+use crate::{
+  check::{self, Answers, Status},
+  cli::{fail, fail_in, Cli},
+  day::Solution,
+  output::{self, Format, Outcome, Record},
+  runner::{self, Settings},
+};
+
+// Known answers of an input are kept next to it, with the same name and an
+// `.answers.toml` extension (`alice.txt` and `alice.answers.toml`).
+const SIDECAR: &str = ".answers.toml";
+
+pub fn sidecar(input: &Path) -> PathBuf {
+  let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+
+  input.with_file_name(format!("{stem}{SIDECAR}"))
+}
+
+// Every input of the directory, in order, leaving out the answers sidecars and
+// hidden files.
+pub fn inputs(dir: &Path) -> io::Result<Vec<PathBuf>> {
+  let mut inputs = fs::read_dir(dir)?
+    .filter_map(|entry| entry.ok())
+    .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_file()))
+    .map(|entry| entry.path())
+    .filter(|path| {
+      let name = path.file_name().unwrap_or_default().to_string_lossy();
+
+      !name.starts_with('.') && !name.ends_with(SIDECAR)
+    })
+    .collect::<Vec<_>>();
+
+  inputs.sort();
+
+  Ok(inputs)
+}
+
+// An input of the batch, with the time it took to parse it, whichever of its
+// parts it is attributed to and whether or not that part could be solved.
+pub struct Row {
+  pub input: String,
+  pub parse: Option<Duration>,
+  pub records: Vec<Record>,
+}
+
+// One row per input, with the answer and solving time of each part. Answers
+// that do not match the sidecar are flagged, and shown in red when `color` is
+// set. Why parts failed is listed under the table, to keep it narrow.
+pub fn table(parts: &[usize], rows: &[Row], color: bool) -> String {
+  let mut header = vec!["Input".to_string(), "Parse".to_string()];
+
+  for part in parts {
+    header.extend([format!("Part {part}"), "Time".to_string()]);
+  }
+
+  header.push(String::new());
+
+  let cells = rows
+    .iter()
+    .map(|Row { input, parse, records }| {
+      let mut row = vec![input.clone(), parse.map(|parse| format!("{parse:.2?}")).unwrap_or_else(|| "-".to_string())];
+      let mut mismatch = false;
+
+      for part in parts {
+        match records.iter().find(|record| record.part == *part) {
+          Some(record) => {
+            let result = match &record.answer {
+              Some(answer) => answer.to_string(),
+              None => record.outcome.to_string(),
+            };
+
+            let answer = match &record.check {
+              Some(Status::Fail { expected }) => {
+                mismatch = true;
+                format!("{result} (expected {expected})")
+              }
+
+              _ => result,
+            };
+
+            row.extend([answer, record.solve.map(|solve| format!("{solve:.2?}")).unwrap_or_else(|| "-".to_string())]);
+          }
+
+          None => row.extend(["-".to_string(), "-".to_string()]),
+        }
+      }
+
+      row.push(if mismatch { "mismatch".to_string() } else { String::new() });
+
+      (row, mismatch)
+    })
+    .collect::<Vec<_>>();
+
+  let widths = (0..header.len())
+    .map(|column| cells.iter().map(|(row, _)| row[column].chars().count()).chain([header[column].len()]).max().unwrap_or(0))
+    .collect::<Vec<_>>();
+
+  let render = |cells: &[String]| cells.iter().zip(&widths).map(|(cell, width)| format!("{cell:<width$}")).join("  ").trim_end().to_string();
+
+  let failures = rows
+    .iter()
+    .flat_map(|Row { input, records, .. }| records.iter().map(move |record| (input, record)))
+    .filter_map(|(input, record)| Some(format!("{input} part {}: {}", record.part, record.message.as_ref()?)))
+    .collect::<Vec<_>>();
+
+  let table = [render(&header), widths.iter().map(|width| "-".repeat(*width)).join("  ").trim_end().to_string()]
+    .into_iter()
+    .chain(cells.iter().map(|(row, mismatch)| match (mismatch, color) {
+      (true, true) => format!("\x1b[1;31m{}\x1b[0m", render(row)),
+      _ => render(row),
+    }))
+    .join("\n");
+
+  match failures.is_empty() {
+    true => table,
+    false => format!("{table}\n\n{}", failures.join("\n")),
+  }
+}
+
+// Runs both parts of a day on every input of a directory, such as the puzzle
+// inputs of several people, checking them against the answers sidecar of each
+// input when there is one.
+pub fn run(cli: &Cli, solutions: &[Solution]) -> ! {
+  let (day, dir) = (cli.day.unwrap_or_default(), cli.dir.clone().unwrap_or_default());
+  let year = cli.year(solutions);
+
+  let Some(solution) = solutions.iter().find(|solution| solution.year == year && solution.day == day) else {
+    fail("Unknown day, come back later! o7");
+  };
+
+  let mut settings = Settings::new(cli);
+
+  runner::check_overrides(&settings.overrides, iter::once(solution));
+
+  let inputs = inputs(Path::new(&dir)).unwrap_or_else(|error| fail(format!("Could not read {dir}: {error}")));
+  let options = runner::configure(solution, false, &settings.overrides).unwrap_or_else(|message| fail(message));
+
+  let mut rows = vec![];
+
+  for input in inputs {
+    let sidecar = sidecar(&input);
+
+    settings.answers = match fs::read_to_string(&sidecar) {
+      Ok(content) => Some(Answers::sidecar(year, day, &content).unwrap_or_else(|error| fail_in(error, &sidecar, &content))),
+      Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+      Err(error) => fail(format!("Could not read {}: {error}", sidecar.display())),
+    };
+
+    settings.input = Some(input.clone());
+
+    let records = runner::execute(solution, &solution.parts(), &options, &settings);
+
+    rows.push(Row {
+      input: input.file_name().unwrap_or_default().to_string_lossy().to_string(),
+      parse: records.iter().find_map(|record| record.parse),
+      records,
+    });
+  }
+
+  match settings.format {
+    Format::Text => println!("{}", table(&solution.parts(), &rows, io::stdout().is_terminal())),
+    format => println!("{}", output::render(format, &rows.iter().flat_map(|row| row.records.clone()).collect::<Vec<_>>())),
+  }
+
+  let records = rows.into_iter().flat_map(|row| row.records).collect::<Vec<_>>();
+  let (_, mismatches, _) = check::summary(&records);
+
+  match mismatches > 0 || records.iter().any(|record| record.outcome != Outcome::Solved) {
+    true => process::exit(1),
+    false => process::exit(0),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{path::Path, time::Duration};
+
+  use super::Row;
+  use crate::{
+    check::Status,
+    output::{Outcome, Record},
+  };
+
+  #[test]
+  fn sidecar() {
+    assert_eq!(super::sidecar(Path::new("inputs/alice.txt")), Path::new("inputs/alice.answers.toml"));
+    assert_eq!(super::sidecar(Path::new("inputs/bob")), Path::new("inputs/bob.answers.toml"));
+  }
+
+  #[test]
+  fn table() {
+    let record = |part: usize, answer: i64, check| Record {
+      answer: Some(answer.into()),
+      solve: Some(Duration::from_micros(5)),
+      check,
+      ..Record::failed(2023, 8, part, Outcome::Solved, None, "alice.txt".to_string(), false)
+    };
+
+    let rows = [
+      Row {
+        input: "alice.txt".to_string(),
+        parse: None,
+        records: vec![record(1, 6, Some(Status::Pass)), record(2, 7, Some(Status::Fail { expected: "6".to_string() }))],
+      },
+      Row {
+        input: "bob.txt".to_string(),
+        parse: Some(Duration::from_micros(3)),
+        records: vec![Record::failed(2023, 8, 1, Outcome::Panicked, Some("not a valid direction".to_string()), "bob.txt".to_string(), false)],
+      },
+    ];
+
+    let table = super::table(&[1, 2], &rows, false);
+    let lines = table.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[2], "alice.txt  -       6         5.00µs  7 (expected 6)  5.00µs  mismatch");
+    assert_eq!(lines[3], "bob.txt    3.00µs  panicked  -       -               -");
+    assert_eq!(lines[5], "bob.txt part 1: not a valid direction");
+    assert!(super::table(&[1, 2], &rows, true).contains("\x1b[1;31malice.txt"));
+  }
+}
@@ -0,0 +1,195 @@
+use std::{
+  hint,
+  time::{Duration, Instant},
+};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+  pub warmup: usize,
+  pub iterations: Option<usize>,
+  pub budget: Option<Duration>,
+}
+
+impl Config {
+  const DEFAULT_ITERATIONS: usize = 10;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+  pub iterations: usize,
+  #[serde(rename = "min_ns", with = "crate::output::nanos")]
+  pub min: Duration,
+  #[serde(rename = "median_ns", with = "crate::output::nanos")]
+  pub median: Duration,
+  #[serde(rename = "mean_ns", with = "crate::output::nanos")]
+  pub mean: Duration,
+  #[serde(rename = "p95_ns", with = "crate::output::nanos")]
+  pub p95: Duration,
+  #[serde(rename = "stddev_ns", with = "crate::output::nanos")]
+  pub stddev: Duration,
+}
+
+// Runs the warmup iterations, then measures until either the iteration count
+// or the time budget is exhausted, whichever comes first. At least one
+// iteration is always measured.
+pub fn measure<F, T>(config: &Config, mut f: F) -> Stats
+where
+  F: FnMut() -> T,
+{
+  for _ in 0..config.warmup {
+    hint::black_box(f());
+  }
+
+  let iterations = match (config.iterations, config.budget) {
+    (Some(iterations), _) => iterations.max(1),
+    (None, Some(_)) => usize::MAX,
+    (None, None) => Config::DEFAULT_ITERATIONS,
+  };
+
+  let start = Instant::now();
+  let mut samples = Vec::new();
+
+  while samples.len() < iterations {
+    let before = Instant::now();
+    hint::black_box(f());
+    samples.push(before.elapsed());
+
+    if config.budget.is_some_and(|budget| start.elapsed() >= budget) {
+      break;
+    }
+  }
+
+  Stats::from_samples(samples)
+}
+
+impl Stats {
+  fn from_samples(mut samples: Vec<Duration>) -> Stats {
+    samples.sort();
+
+    let count = samples.len();
+    let seconds = samples.iter().map(Duration::as_secs_f64).collect::<Vec<_>>();
+    let mean = seconds.iter().sum::<f64>() / count as f64;
+
+    let variance = match count {
+      1 => 0.0,
+      _ => seconds.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>() / (count - 1) as f64,
+    };
+
+    let median = match count % 2 {
+      0 => (samples[count / 2 - 1] + samples[count / 2]) / 2,
+      _ => samples[count / 2],
+    };
+
+    Stats {
+      iterations: count,
+      min: samples[0],
+      median,
+      mean: Duration::from_secs_f64(mean),
+      p95: samples[(count * 95).div_ceil(100) - 1],
+      stddev: Duration::from_secs_f64(variance.sqrt()),
+    }
+  }
+}
+
+// Accepts `500ms`, `2s`, `1.5s` or a bare number of seconds.
+pub fn parse_duration(value: &str) -> Option<Duration> {
+  let (number, unit) = match value.find(|c: char| !c.is_ascii_digit() && c != '.') {
+    Some(index) => value.split_at(index),
+    None => (value, "s"),
+  };
+
+  let number = number.trim().parse::<f64>().ok().filter(|number| number.is_finite() && *number >= 0.0)?;
+
+  match unit.trim() {
+    "ns" => Some(Duration::from_secs_f64(number / 1e9)),
+    "us" | "µs" => Some(Duration::from_secs_f64(number / 1e6)),
+    "ms" => Some(Duration::from_secs_f64(number / 1e3)),
+    "s" => Some(Duration::from_secs_f64(number)),
+    "m" => Some(Duration::from_secs_f64(number * 60.0)),
+    _ => None,
+  }
+}
+
+pub fn table(rows: &[(usize, usize, usize, Stats)]) -> String {
+  let header = ["Year", "Day", "Part", "Iterations", "Min", "Median", "Mean", "P95", "Std dev"];
+  let mut lines = rows
+    .iter()
+    .map(|(year, day, part, stats)| {
+      vec![
+        year.to_string(),
+        format!("{day:0>2}"),
+        format!("{part:0>2}"),
+        stats.iterations.to_string(),
+        format!("{:.2?}", stats.min),
+        format!("{:.2?}", stats.median),
+        format!("{:.2?}", stats.mean),
+        format!("{:.2?}", stats.p95),
+        format!("{:.2?}", stats.stddev),
+      ]
+    })
+    .collect::<Vec<_>>();
+
+  // Runs are independent, so their variances add up.
+  let total = |f: fn(&Stats) -> Duration| rows.iter().map(|(_, _, _, stats)| f(stats)).sum::<Duration>();
+  let stddev = rows.iter().map(|(_, _, _, stats)| stats.stddev.as_secs_f64().powi(2)).sum::<f64>().sqrt();
+
+  lines.push(vec![
+    "Total".to_string(),
+    String::new(),
+    String::new(),
+    rows.iter().map(|(_, _, _, stats)| stats.iterations).sum::<usize>().to_string(),
+    format!("{:.2?}", total(|stats| stats.min)),
+    format!("{:.2?}", total(|stats| stats.median)),
+    format!("{:.2?}", total(|stats| stats.mean)),
+    format!("{:.2?}", total(|stats| stats.p95)),
+    format!("{:.2?}", Duration::from_secs_f64(stddev)),
+  ]);
+
+  let widths = (0..header.len())
+    .map(|column| lines.iter().map(|line| line[column].chars().count()).chain([header[column].len()]).max().unwrap_or(0))
+    .collect::<Vec<_>>();
+
+  let render = |cells: &[String]| cells.iter().zip(&widths).map(|(cell, width)| format!("{cell:>width$}")).join("  ");
+  let separator = widths.iter().map(|width| "-".repeat(*width)).join("  ");
+
+  let mut output = vec![render(&header.map(String::from)), separator.clone()];
+  let total = lines.pop().unwrap_or_default();
+
+  output.extend(lines.iter().map(|line| render(line)));
+  output.push(separator);
+  output.push(render(&total));
+
+  output.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use super::Stats;
+
+  #[test]
+  fn stats() {
+    let samples = [4, 1, 3, 2, 10].map(Duration::from_millis).to_vec();
+    let stats = Stats::from_samples(samples);
+
+    assert_eq!(stats.iterations, 5);
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_millis(3));
+    assert_eq!(stats.mean.as_millis(), 4);
+    assert_eq!(stats.p95, Duration::from_millis(10));
+    assert_eq!(stats.stddev.as_micros(), 3535);
+  }
+
+  #[test]
+  fn parse_duration() {
+    assert_eq!(super::parse_duration("500ms"), Some(Duration::from_millis(500)));
+    assert_eq!(super::parse_duration("1.5s"), Some(Duration::from_millis(1500)));
+    assert_eq!(super::parse_duration("250µs"), Some(Duration::from_micros(250)));
+    assert_eq!(super::parse_duration("2"), Some(Duration::from_secs(2)));
+    assert_eq!(super::parse_duration("2 weeks"), None);
+  }
+}
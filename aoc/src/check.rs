@@ -0,0 +1,220 @@
+use std::{
+  collections::{BTreeMap, HashMap},
+  io,
+  path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use toml::{Spanned, Value};
+
+use crate::{
+  error::{Error, Result},
+  output::Record,
+  util,
+};
+
+// Known answers live next to the inputs of their year, as `[dayNN]` tables
+// holding `part1` and `part2` keys, the answers to the examples being kept in
+// a separate file:
+//
+//   [day05]
+//   part1 = 35
+//   part2 = "46"
+pub fn answers_file(year: usize, test: bool) -> PathBuf {
+  match test {
+    true => util::year_dir(year).join("answers_test.toml"),
+    false => util::year_dir(year).join("answers.toml"),
+  }
+}
+
+// Known answers of every loaded year, by year, day and part.
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<(usize, usize, usize), String>);
+
+type Table = BTreeMap<Spanned<String>, BTreeMap<Spanned<String>, Spanned<Value>>>;
+
+impl Answers {
+  // A year without an answers file has no known answers yet, every part of it
+  // is reported as missing one.
+  pub fn load(year: usize, file: &Path) -> Result<Answers> {
+    match util::read_data(file) {
+      Ok(content) => Answers::parse(year, &content),
+      Err(source) if source.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+      Err(source) => Err(Error::Io {
+        file: util::source_name(file),
+        source,
+      }),
+    }
+  }
+
+  pub fn parse(year: usize, content: &str) -> Result<Answers> {
+    let error = |span, expected: &str| Error::at_offset(content, span, expected);
+
+    let table = toml::from_str::<Table>(content).map_err(|err| error(err.span().unwrap_or(0..0), &format!("valid TOML ({})", err.message().trim())))?;
+    let mut answers = HashMap::new();
+
+    for (day, parts) in table {
+      let Some(number) = day.get_ref().strip_prefix("day").and_then(|number| number.parse::<usize>().ok()) else {
+        return Err(error(day.span(), "a `dayNN` table"));
+      };
+
+      for (part, answer) in parts {
+        let (part, answer) = Answers::part(content, part, answer)?;
+
+        answers.insert((year, number, part), answer);
+      }
+    }
+
+    Ok(Answers(answers))
+  }
+
+  // Answers of a single input, kept in a sidecar file next to it, as a flat
+  // table of `part1` and `part2` keys.
+  pub fn sidecar(year: usize, day: usize, content: &str) -> Result<Answers> {
+    let table =
+      toml::from_str::<BTreeMap<Spanned<String>, Spanned<Value>>>(content).map_err(|err| Error::at_offset(content, err.span().unwrap_or(0..0), format!("valid TOML ({})", err.message().trim())))?;
+
+    table
+      .into_iter()
+      .map(|(part, answer)| Answers::part(content, part, answer).map(|(part, answer)| ((year, day, part), answer)))
+      .collect::<Result<_>>()
+      .map(Answers)
+  }
+
+  fn part(content: &str, part: Spanned<String>, answer: Spanned<Value>) -> Result<(usize, String)> {
+    let part_number = match part.get_ref().as_str() {
+      "part1" => 1,
+      "part2" => 2,
+      _ => return Err(Error::at_offset(content, part.span(), "one of `part1` or `part2`")),
+    };
+
+    let answer_text = match answer.get_ref() {
+      Value::Integer(value) => value.to_string(),
+      Value::String(value) => value.clone(),
+      _ => return Err(Error::at_offset(content, answer.span(), "an integer or a string")),
+    };
+
+    Ok((part_number, answer_text))
+  }
+
+  pub fn extend(&mut self, other: Answers) {
+    self.0.extend(other.0);
+  }
+
+  pub fn get(&self, year: usize, day: usize, part: usize) -> Option<&str> {
+    self.0.get(&(year, day, part)).map(String::as_str)
+  }
+
+  pub fn check(&self, record: &Record) -> Status {
+    match self.0.get(&(record.year, record.day, record.part)) {
+      None => Status::Missing,
+      Some(expected) if record.answer.as_ref().is_some_and(|answer| *expected == answer.to_string()) => Status::Pass,
+      Some(expected) => Status::Fail { expected: expected.clone() },
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum Status {
+  Pass,
+  Fail { expected: String },
+  Missing,
+}
+
+pub fn summary(records: &[Record]) -> (usize, usize, usize) {
+  records.iter().fold((0, 0, 0), |(pass, fail, missing), record| match record.check {
+    Some(Status::Pass) => (pass + 1, fail, missing),
+    Some(Status::Fail { .. }) => (pass, fail + 1, missing),
+    Some(Status::Missing) => (pass, fail, missing + 1),
+    None => (pass, fail, missing),
+  })
+}
+
+pub fn junit(records: &[Record]) -> String {
+  let (_, failures, skipped) = summary(records);
+  let escape = |text: &str| text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;");
+
+  let mut xml = vec![
+    r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string(),
+    format!(r#"<testsuites tests="{}" failures="{failures}" skipped="{skipped}">"#, records.len()),
+    format!(r#"  <testsuite name="aoc" tests="{}" failures="{failures}" skipped="{skipped}">"#, records.len()),
+  ];
+
+  for record in records {
+    let time = record.steps().iter().map(|(_, duration)| duration.as_secs_f64()).sum::<f64>();
+    let testcase = format!(r#"    <testcase classname="{}.day{:0>2}" name="part{}" time="{time:.6}""#, record.year, record.day, record.part);
+
+    match &record.check {
+      Some(Status::Fail { expected }) => {
+        xml.push(format!("{testcase}>"));
+        xml.push(format!(r#"      <failure message="expected {}, got {}"/>"#, escape(expected), escape(&record.result())));
+        xml.push("    </testcase>".to_string());
+      }
+
+      Some(Status::Missing) => {
+        xml.push(format!("{testcase}>"));
+        xml.push(r#"      <skipped message="no known answer"/>"#.to_string());
+        xml.push("    </testcase>".to_string());
+      }
+
+      _ => xml.push(format!("{testcase}/>")),
+    }
+  }
+
+  xml.push("  </testsuite>".to_string());
+  xml.push("</testsuites>".to_string());
+  xml.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Answers, Status};
+  use crate::error::Error;
+
+  #[test]
+  fn parse() {
+    let answers = Answers::parse(2023, "[day05]\npart1 = 35\npart2 = \"46\"\n\n[day6]\npart1 = 288\n").unwrap();
+
+    assert_eq!(answers.0.get(&(2023, 5, 1)).map(String::as_str), Some("35"));
+    assert_eq!(answers.0.get(&(2023, 5, 2)).map(String::as_str), Some("46"));
+    assert_eq!(answers.0.get(&(2023, 6, 1)).map(String::as_str), Some("288"));
+    assert_eq!(answers.0.get(&(2023, 6, 2)), None);
+  }
+
+  #[test]
+  fn parse_errors() {
+    let Err(Error::Parse(error)) = Answers::parse(2023, "[day05]\npart3 = 35\n") else { panic!() };
+
+    assert_eq!((error.line, error.column, error.span), (2, 1, 5));
+    assert_eq!(error.expected, "one of `part1` or `part2`");
+
+    let Err(Error::Parse(error)) = Answers::parse(2023, "[day05]\npart1 = 3.5\n") else { panic!() };
+
+    assert_eq!((error.line, error.column), (2, 9));
+  }
+
+  #[test]
+  fn load_missing() {
+    let answers = Answers::load(2023, std::path::Path::new("data/1970/answers.toml")).unwrap();
+
+    assert_eq!(answers.get(2023, 1, 1), None);
+  }
+
+  #[test]
+  fn sidecar() {
+    let answers = Answers::sidecar(2023, 8, "part1 = 6\npart2 = \"6\"\n").unwrap();
+
+    assert_eq!(answers.get(2023, 8, 1), Some("6"));
+    assert_eq!(answers.get(2023, 8, 2), Some("6"));
+
+    let Err(Error::Parse(error)) = Answers::sidecar(2023, 8, "part1 = 6\n[day08]\n") else { panic!() };
+
+    assert_eq!(error.line, 2);
+  }
+
+  #[test]
+  fn status() {
+    assert_eq!(serde_json::to_string(&Status::Fail { expected: "46".to_string() }).unwrap(), r#"{"status":"fail","expected":"46"}"#);
+  }
+}
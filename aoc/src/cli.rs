@@ -0,0 +1,324 @@
+use std::{fmt, io, path::Path, process};
+
+use argparse::{ArgumentParser, Collect, IncrBy, Store, StoreOption, StoreTrue};
+
+use crate::{day::Solution, error::Error, options::Setting, output::Format, util};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+  Run,
+  Compare,
+  New,
+  Batch,
+  Repl,
+  Fetch,
+  Submit,
+  Identify,
+}
+
+// Command line of the runner or of one of its subcommands, each only setting
+// the fields it takes.
+pub struct Cli {
+  pub command: Command,
+  pub year: Option<usize>,
+  pub day: Option<usize>,
+  pub part: Option<usize>,
+  pub test: bool,
+  pub timings: bool,
+  // Input file, `-` being stdin.
+  pub input: Option<String>,
+  pub data_dir: Option<String>,
+  pub bench: bool,
+  pub warmup: usize,
+  pub iterations: Option<usize>,
+  pub budget: Option<String>,
+  pub format: Format,
+  pub check: bool,
+  pub junit: Option<String>,
+  pub no_history: bool,
+  pub baseline: Option<String>,
+  pub timeout: Option<String>,
+  pub memory_limit: Option<String>,
+  pub jobs: Option<usize>,
+  pub uncontended: bool,
+  pub watch: bool,
+  pub verbose: u8,
+  pub trace: Option<String>,
+  pub set: Vec<String>,
+  pub list_options: bool,
+  // Slowdown, in percent, above which `compare` flags a part.
+  pub threshold: f64,
+  pub force: bool,
+  pub answer: Option<String>,
+  pub dir: Option<String>,
+  pub run: bool,
+}
+
+impl Default for Cli {
+  fn default() -> Cli {
+    Cli {
+      command: Command::Run,
+      year: None,
+      day: None,
+      part: None,
+      test: false,
+      timings: false,
+      input: None,
+      data_dir: None,
+      bench: false,
+      warmup: 3,
+      iterations: None,
+      budget: None,
+      format: Format::Text,
+      check: false,
+      junit: None,
+      no_history: false,
+      baseline: None,
+      timeout: None,
+      memory_limit: None,
+      jobs: None,
+      uncontended: false,
+      watch: false,
+      verbose: 0,
+      trace: None,
+      set: vec![],
+      list_options: false,
+      threshold: 10.0,
+      force: false,
+      answer: None,
+      dir: None,
+      run: false,
+    }
+  }
+}
+
+impl Cli {
+  // Subcommands are named by the first argument, and parsed as if they were a
+  // program of their own (`aoc batch`).
+  pub fn parse(arguments: Vec<String>) -> Cli {
+    let command = match arguments.get(1).map(String::as_str) {
+      Some("compare") => Command::Compare,
+      Some("new") => Command::New,
+      Some("batch") => Command::Batch,
+      Some("repl") => Command::Repl,
+      Some("fetch") => Command::Fetch,
+      Some("submit") => Command::Submit,
+      Some("identify") => Command::Identify,
+      _ => Command::Run,
+    };
+
+    let arguments = match command {
+      Command::Run => arguments,
+      _ => [format!("{} {}", arguments[0], arguments[1])].into_iter().chain(arguments.into_iter().skip(2)).collect(),
+    };
+
+    let mut cli = Cli { command, ..Cli::default() };
+
+    {
+      let mut args = ArgumentParser::new();
+
+      match command {
+        Command::Run => cli.run_arguments(&mut args),
+        Command::Compare => cli.compare_arguments(&mut args),
+        Command::New => cli.new_arguments(&mut args),
+        Command::Batch => cli.batch_arguments(&mut args),
+        Command::Repl => cli.repl_arguments(&mut args),
+        Command::Fetch => cli.fetch_arguments(&mut args),
+        Command::Submit => cli.submit_arguments(&mut args),
+        Command::Identify => cli.identify_arguments(&mut args),
+      }
+
+      if let Err(code) = args.parse(arguments, &mut io::stdout(), &mut io::stderr()) {
+        process::exit(code);
+      }
+    }
+
+    cli
+  }
+
+  fn run_arguments<'a>(&'a mut self, args: &mut ArgumentParser<'a>) {
+    let Cli {
+      year,
+      day,
+      part,
+      test,
+      timings,
+      input,
+      data_dir,
+      bench,
+      warmup,
+      iterations,
+      budget,
+      format,
+      check,
+      junit,
+      no_history,
+      baseline,
+      timeout,
+      memory_limit,
+      jobs,
+      uncontended,
+      watch,
+      verbose,
+      trace,
+      set,
+      list_options,
+      ..
+    } = self;
+
+    args
+      .set_description("Run the puzzle solutions, of every year or of the given year, day and part (e.g. `aoc 2023 5 2`). `compare` compares the timings of the latest runs, `new` starts a new day, `batch` runs a day on a directory of inputs, `repl` starts an interactive session, `fetch` and `submit` download inputs and send answers, `identify` tells which day an input is for.");
+    args.refer(test).add_option(&["-t", "--test"], StoreTrue, "run with test input");
+    args.refer(timings).add_option(&["--timings"], StoreTrue, "run with timings");
+    args.refer(input).add_option(&["-i", "--input"], StoreOption, "read the input from this file (- for stdin)");
+    args.refer(data_dir).add_option(&["--data-dir"], StoreOption, "look for input files in this directory");
+    args.refer(bench).add_option(&["--bench"], StoreTrue, "benchmark the solving of each part");
+    args.refer(warmup).add_option(&["--warmup"], Store, "unmeasured iterations to run first when benchmarking (default: 3)");
+    args.refer(iterations).add_option(&["--iterations"], StoreOption, "measured iterations when benchmarking (default: 10)");
+    args.refer(budget).add_option(&["--budget"], StoreOption, "time budget per part when benchmarking (e.g. 500ms, 2s)");
+    args.refer(format).add_option(&["--format"], Store, "output format: text, json, csv or markdown (default: text)");
+    args.refer(check).add_option(&["--check"], StoreTrue, "compare the answers against the known ones");
+    args
+      .refer(junit)
+      .add_option(&["--junit"], StoreOption, "write a JUnit XML report of the check to this file (implies --check)");
+    args.refer(no_history).add_option(&["--no-history"], StoreTrue, "do not record the timings of this run");
+    args.refer(baseline).add_option(&["--baseline"], StoreOption, "record the timings of this run as the named baseline");
+    args
+      .refer(timeout)
+      .add_option(&["--timeout"], StoreOption, "wall-clock time limit per part (e.g. 30s), runs each part in its own process");
+    args
+      .refer(memory_limit)
+      .add_option(&["--memory-limit"], StoreOption, "memory limit per part (e.g. 512M), runs each part in its own process");
+    args.refer(jobs).add_option(&["-j", "--jobs"], StoreOption, "run days and parts concurrently on this many threads");
+    args
+      .refer(uncontended)
+      .add_option(&["--uncontended"], StoreTrue, "never measure two parts at the same time when running concurrently");
+    args
+      .refer(watch)
+      .add_option(&["--watch"], StoreTrue, "rebuild and run the day on its example and real inputs whenever its sources or inputs change");
+    args.refer(set).add_option(&["--set"], Collect, "set an option of the day, as name=value (e.g. --set steps=26501365)");
+    args
+      .refer(list_options)
+      .add_option(&["--list-options"], StoreTrue, "list the options of the selected days, with their defaults");
+    args
+      .refer(verbose)
+      .add_option(&["-v", "--verbose"], IncrBy(1), "trace what long-running parts are doing on stderr, -vv for more details");
+    args
+      .refer(trace)
+      .add_option(&["--trace"], StoreOption, "write the trace to this file instead, as JSON lines (implies -v)");
+    args.refer(year).add_argument("YEAR", StoreOption, "year of the event");
+    args.refer(day).add_argument("DAY", StoreOption, "day of the month");
+    args.refer(part).add_argument("PART", StoreOption, "puzzle part");
+  }
+
+  fn compare_arguments<'a>(&'a mut self, args: &mut ArgumentParser<'a>) {
+    let Cli { test, baseline, threshold, .. } = self;
+
+    args.set_description("Compare the latest timings against the previous run or a named baseline.");
+    args.refer(test).add_option(&["-t", "--test"], StoreTrue, "compare the runs on test input");
+    args
+      .refer(baseline)
+      .add_option(&["--baseline"], StoreOption, "compare against the latest run recorded as this baseline");
+    args
+      .refer(threshold)
+      .add_option(&["--threshold"], Store, "slowdown, in percent, above which a part is flagged (default: 10)");
+  }
+
+  fn new_arguments<'a>(&'a mut self, args: &mut ArgumentParser<'a>) {
+    let Cli { year, day, .. } = self;
+
+    args.set_description("Start a new day from the template, with empty example and puzzle inputs.");
+    args.refer(year).add_option(&["--year"], StoreOption, "year of the event (default: the latest one with solutions)");
+    args.refer(day).add_argument("DAY", StoreOption, "day of the month").required();
+  }
+
+  fn batch_arguments<'a>(&'a mut self, args: &mut ArgumentParser<'a>) {
+    let Cli { year, day, format, set, dir, .. } = self;
+
+    args.set_description("Run a day on every input of a directory, checking them against their answers sidecar if any.");
+    args.refer(year).add_option(&["--year"], StoreOption, "year of the event (default: the latest one with solutions)");
+    args.refer(format).add_option(&["--format"], Store, "output format: text, json, csv or markdown");
+    args.refer(set).add_option(&["--set"], Collect, "set an option of the day, as name=value");
+    args.refer(day).add_argument("DAY", StoreOption, "day of the month").required();
+    args.refer(dir).add_argument("DIR", StoreOption, "directory holding the inputs").required();
+  }
+
+  fn repl_arguments<'a>(&'a mut self, args: &mut ArgumentParser<'a>) {
+    let Cli { year, .. } = self;
+
+    args.set_description("Run days interactively, keeping their inputs parsed between runs. Type `help` for the commands.");
+    args.refer(year).add_option(&["--year"], StoreOption, "year of the event (default: the latest one with solutions)");
+  }
+
+  fn fetch_arguments<'a>(&'a mut self, args: &mut ArgumentParser<'a>) {
+    let Cli { year, day, force, .. } = self;
+
+    args.set_description("Download the puzzle input of a day into the data directory.");
+    args.refer(year).add_option(&["--year"], StoreOption, "year of the event (default: the latest one with solutions)");
+    args.refer(force).add_option(&["--force"], StoreTrue, "download the input again even if it is already there");
+    args.refer(day).add_argument("DAY", StoreOption, "day of the month").required();
+  }
+
+  fn submit_arguments<'a>(&'a mut self, args: &mut ArgumentParser<'a>) {
+    let Cli { year, day, part, answer, .. } = self;
+
+    args.set_description("Submit the answer to a part, solving it on the puzzle input unless it is given.");
+    args.refer(year).add_option(&["--year"], StoreOption, "year of the event (default: the latest one with solutions)");
+    args.refer(answer).add_option(&["--answer"], StoreOption, "answer to submit instead of solving the part");
+    args.refer(day).add_argument("DAY", StoreOption, "day of the month").required();
+    args.refer(part).add_argument("PART", StoreOption, "part of the puzzle").required();
+  }
+
+  fn identify_arguments<'a>(&'a mut self, args: &mut ArgumentParser<'a>) {
+    let Cli { year, run, input, .. } = self;
+
+    args.set_description("Tell which day an input is for, with how confident the guess is.");
+    args.refer(year).add_option(&["--year"], StoreOption, "only consider the days of this year");
+    args.refer(run).add_option(&["--run"], StoreTrue, "run the most likely day on the input");
+    args.refer(input).add_argument("FILE", StoreOption, "input file").required();
+  }
+
+  // The given year, or the latest one with solutions.
+  pub fn year(&self, solutions: &[Solution]) -> usize {
+    self.year.map_or_else(|| default_year(solutions), Ok).unwrap_or_else(|message| fail(message))
+  }
+
+  // Options set with `--set`, to be applied over the defaults of each day.
+  pub fn overrides(&self) -> Vec<Setting> {
+    self
+      .set
+      .iter()
+      .map(|text| Setting::parse(text).unwrap_or_else(|| fail(format!("Invalid option `{text}`, expected name=value."))))
+      .collect()
+  }
+}
+
+// Subcommands working on a single year default to the latest one with
+// solutions, there being none when no day is registered.
+pub fn default_year(solutions: &[Solution]) -> Result<usize, &'static str> {
+  solutions
+    .iter()
+    .map(|solution| solution.year)
+    .max()
+    .ok_or("There are no solutions yet, the year must be given with --year.")
+}
+
+// Reports what prevents the command from going any further, and exits.
+pub fn fail(message: impl fmt::Display) -> ! {
+  eprintln!("{message}");
+  process::exit(1);
+}
+
+// Same, for an error located in a file, quoting the offending line from its
+// content.
+pub fn fail_in(error: Error, file: &Path, content: &str) -> ! {
+  fail(error.in_file(&util::source_name(file)).render(content))
+}
+
+#[cfg(test)]
+mod tests {
+  #[test]
+  fn default_year() {
+    assert!(super::default_year(&[]).is_err());
+  }
+}
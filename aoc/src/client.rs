@@ -0,0 +1,417 @@
+use std::{
+  env, fmt, fs, io,
+  path::{Path, PathBuf},
+  process, thread,
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::Deserialize;
+
+use crate::{
+  cli::{fail, fail_in, Cli},
+  day::Solution,
+  error::{Error, Result},
+  isolate, runner, util,
+};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!("github.com/apognu/aoc2023 ", env!("CARGO_PKG_VERSION"));
+
+// Settings of the client, which hold the session cookie and are therefore kept
+// out of the repository, in `$AOC_CONFIG` or `~/.config/aoc/config.toml`:
+//
+//   session = "53616c7465645f5f..."
+//   # base_url = "http://localhost:8080"
+//   # interval = 5
+//
+// The session and the base URL can also be given as `$AOC_SESSION` and
+// `$AOC_BASE_URL`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+  pub session: Option<String>,
+  pub base_url: Option<String>,
+  // Seconds to wait at least between two requests.
+  pub interval: Option<f64>,
+}
+
+pub fn config_file() -> PathBuf {
+  if let Some(file) = env::var_os("AOC_CONFIG").filter(|file| !file.is_empty()) {
+    return PathBuf::from(file);
+  }
+
+  match env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+    Some(dir) => PathBuf::from(dir).join("aoc/config.toml"),
+    None => PathBuf::from(env::var_os("HOME").unwrap_or_default()).join(".config/aoc/config.toml"),
+  }
+}
+
+impl Config {
+  // The file is optional, as long as the environment provides the session.
+  pub fn load(file: &Path) -> Result<Config> {
+    let content = match fs::read_to_string(file) {
+      Ok(content) => content,
+      Err(source) if source.kind() == io::ErrorKind::NotFound => String::new(),
+      Err(source) => {
+        return Err(Error::Io {
+          file: util::source_name(file),
+          source,
+        })
+      }
+    };
+
+    let mut config = toml::from_str::<Config>(&content).map_err(|err| Error::at_offset(&content, err.span().unwrap_or(0..0), format!("a valid configuration ({})", err.message().trim())))?;
+
+    if let Some(session) = env::var("AOC_SESSION").ok().filter(|session| !session.is_empty()) {
+      config.session = Some(session);
+    }
+
+    if let Some(base_url) = env::var("AOC_BASE_URL").ok().filter(|base_url| !base_url.is_empty()) {
+      config.base_url = Some(base_url);
+    }
+
+    Ok(config)
+  }
+}
+
+// What the answer page said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+  Accepted,
+  TooHigh,
+  TooLow,
+  Wrong,
+  // Answers were submitted too recently, for how long to wait if known.
+  Wait(Option<Duration>),
+  // The part was already solved, or is not unlocked yet.
+  WrongLevel,
+  Unknown(String),
+}
+
+impl fmt::Display for Verdict {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Verdict::Accepted => write!(f, "accepted, that's the right answer"),
+      Verdict::TooHigh => write!(f, "wrong, the answer is too high"),
+      Verdict::TooLow => write!(f, "wrong, the answer is too low"),
+      Verdict::Wrong => write!(f, "wrong"),
+      Verdict::Wait(Some(wait)) => write!(f, "answered too recently, wait {}s", wait.as_secs()),
+      Verdict::Wait(None) => write!(f, "answered too recently, wait a bit"),
+      Verdict::WrongLevel => write!(f, "this part is either already solved or still locked"),
+      Verdict::Unknown(text) => write!(f, "unexpected response: {text}"),
+    }
+  }
+}
+
+// Reads the verdict from the text of the article of the answer page.
+pub fn verdict(page: &str) -> Verdict {
+  let text = article(page);
+
+  if text.contains("That's the right answer") {
+    Verdict::Accepted
+  } else if text.contains("You gave an answer too recently") {
+    Verdict::Wait(wait(&text))
+  } else if text.contains("your answer is too high") {
+    Verdict::TooHigh
+  } else if text.contains("your answer is too low") {
+    Verdict::TooLow
+  } else if text.contains("That's not the right answer") {
+    Verdict::Wrong
+  } else if text.contains("You don't seem to be solving the right level") {
+    Verdict::WrongLevel
+  } else {
+    Verdict::Unknown(text)
+  }
+}
+
+// The text of the first `<article>`, or of the whole page, without its tags.
+fn article(page: &str) -> String {
+  let page = match (page.find("<article"), page.find("</article>")) {
+    (Some(start), Some(end)) if start < end => &page[start..end],
+    _ => page,
+  };
+
+  let mut text = String::new();
+  let mut tag = false;
+
+  for c in page.chars() {
+    match c {
+      '<' => tag = true,
+      '>' => tag = false,
+      c if !tag => text.push(c),
+      _ => {}
+    }
+  }
+
+  text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// Parses "You have 1m 5s left to wait".
+fn wait(text: &str) -> Option<Duration> {
+  let (_, rest) = text.split_once("You have ")?;
+  let (wait, _) = rest.split_once(" left to wait")?;
+
+  wait.split_whitespace().try_fold(Duration::ZERO, |total, amount| {
+    let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+    let number = number.parse::<u64>().ok()?;
+
+    match unit {
+      "h" => Some(total + Duration::from_secs(number * 3600)),
+      "m" => Some(total + Duration::from_secs(number * 60)),
+      "s" => Some(total + Duration::from_secs(number)),
+      _ => None,
+    }
+  })
+}
+
+pub struct Client {
+  agent: ureq::Agent,
+  base_url: String,
+  session: String,
+  interval: Duration,
+  // Holds the time of the last request, shared by all runs.
+  stamp: PathBuf,
+}
+
+impl Client {
+  pub fn new(config: Config, stamp: PathBuf) -> std::result::Result<Client, String> {
+    let session = config
+      .session
+      .ok_or_else(|| format!("No session cookie, set `session` in {} or $AOC_SESSION.", config_file().display()))?;
+
+    Ok(Client {
+      agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).timeout(Duration::from_secs(30)).build(),
+      base_url: config.base_url.unwrap_or_else(|| BASE_URL.to_string()).trim_end_matches('/').to_string(),
+      session,
+      interval: Duration::from_secs_f64(config.interval.unwrap_or(5.0).max(0.0)),
+      stamp,
+    })
+  }
+
+  // Waits until the interval since the last request, from any run, is over.
+  fn throttle(&self) {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    let last = fs::read_to_string(&self.stamp).ok().and_then(|stamp| stamp.trim().parse::<f64>().ok()).map(Duration::from_secs_f64);
+
+    if let Some(wait) = last.and_then(|last| (last + self.interval).checked_sub(now)).filter(|wait| !wait.is_zero()) {
+      eprintln!("Waiting {:.1}s before sending the request...", wait.as_secs_f64());
+      thread::sleep(wait);
+    }
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    let _ = fs::write(&self.stamp, now.as_secs_f64().to_string());
+  }
+
+  fn send(&self, request: ureq::Request, form: Option<&[(&str, &str)]>) -> std::result::Result<String, String> {
+    self.throttle();
+
+    let request = request.set("Cookie", &format!("session={}", self.session));
+    let url = request.url().to_string();
+
+    let response = match form {
+      Some(form) => request.send_form(form),
+      None => request.call(),
+    };
+
+    match response {
+      Ok(response) => response.into_string().map_err(|error| format!("Could not read the response from {url}: {error}")),
+      Err(ureq::Error::Status(404, _)) => Err(format!("{url} was not found, the puzzle is probably not unlocked yet.")),
+      Err(ureq::Error::Status(400 | 500, _)) => Err(format!("{url} refused the request, the session cookie is probably invalid or expired.")),
+      Err(ureq::Error::Status(status, _)) => Err(format!("{url} answered with status {status}.")),
+      Err(error) => Err(format!("Could not reach {url}: {error}")),
+    }
+  }
+
+  pub fn input(&self, year: usize, day: usize) -> std::result::Result<String, String> {
+    self.send(self.agent.get(&format!("{}/{year}/day/{day}/input", self.base_url)), None)
+  }
+
+  pub fn submit(&self, year: usize, day: usize, part: usize, answer: &str) -> std::result::Result<Verdict, String> {
+    let page = self.send(
+      self.agent.post(&format!("{}/{year}/day/{day}/answer", self.base_url)),
+      Some(&[("level", &part.to_string()), ("answer", answer)]),
+    )?;
+
+    Ok(verdict(&page))
+  }
+}
+
+// Client with the settings from the configuration file, and the time of the
+// last request kept in the data directory.
+fn configured() -> Client {
+  let file = config_file();
+  let config = Config::load(&file).unwrap_or_else(|error| fail_in(error, &file, &fs::read_to_string(&file).unwrap_or_default()));
+
+  Client::new(config, util::data_dir().join(".last_request")).unwrap_or_else(|error| fail(error))
+}
+
+// Downloads the puzzle input of a day where the runner expects it, unless it is
+// already there.
+pub fn fetch(cli: &Cli, solutions: &[Solution]) -> ! {
+  let day = cli.day.unwrap_or_default();
+  let year = cli.year(solutions);
+
+  if !(1..=25).contains(&day) {
+    fail("Days go from 1 to 25.");
+  }
+
+  let file = util::input_file(year, day, 1, false);
+
+  // `new` leaves an empty input behind, to be replaced.
+  if fs::metadata(&file).is_ok_and(|metadata| metadata.len() > 0) && !cli.force {
+    println!("{} is already there.", file.display());
+    process::exit(0);
+  }
+
+  let input = configured().input(year, day).unwrap_or_else(|error| fail(error));
+
+  if let Err(error) = file.parent().map_or(Ok(()), fs::create_dir_all).and_then(|_| fs::write(&file, input)) {
+    fail(format!("Could not write {}: {error}", file.display()));
+  }
+
+  println!("Saved the input to {}", file.display());
+  process::exit(0);
+}
+
+// Solves a part on the puzzle input, or takes the given answer, and submits it.
+pub fn submit(cli: &Cli, solutions: &[Solution]) -> ! {
+  let (day, part) = (cli.day.unwrap_or_default(), cli.part.unwrap_or_default());
+  let year = cli.year(solutions);
+
+  if !(1..=2).contains(&part) {
+    fail("Parts are either 1 or 2.");
+  }
+
+  let answer = cli.answer.clone().unwrap_or_else(|| {
+    let Some(solution) = solutions.iter().find(|solution| solution.year == year && solution.day == day && solution.has_part(part)) else {
+      fail(format!("Day {day} part {part} is not solved yet, give the answer with --answer."));
+    };
+
+    let file = util::input_file(year, day, part, false);
+    let (input, _) = runner::load(solution, &file).unwrap_or_else(|(error, input)| fail_in(error, &file, input.text()));
+    let options = runner::configure(solution, false, &[]).unwrap_or_else(|message| fail(message));
+
+    let answer = isolate::catch(|| solution.solve(part, &input, &options)).unwrap_or_else(|panic| fail(format!("Day {day} part {part} panicked: {panic}")));
+
+    answer.to_string()
+  });
+
+  println!("Submitting {answer} for {year} day {day} part {part}...");
+
+  match configured().submit(year, day, part, &answer) {
+    Ok(verdict @ Verdict::Accepted) => {
+      println!("{verdict}");
+      process::exit(0);
+    }
+
+    Ok(verdict) => {
+      println!("{verdict}");
+      process::exit(1);
+    }
+
+    Err(error) => fail(error),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{
+    env,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread,
+    time::Duration,
+  };
+
+  use super::{Client, Config, Verdict};
+
+  #[test]
+  fn verdicts() {
+    let page = |text: &str| format!("<html><main><article><p>{text}</p></article></main></html>");
+
+    assert_eq!(super::verdict(&page("That's the right answer! You are <em>one gold star</em> closer.")), Verdict::Accepted);
+    assert_eq!(super::verdict(&page("That's not the right answer; your answer is too high.")), Verdict::TooHigh);
+    assert_eq!(super::verdict(&page("That's not the right answer; your answer is too low.")), Verdict::TooLow);
+    assert_eq!(super::verdict(&page("That's not the right answer.")), Verdict::Wrong);
+    assert_eq!(
+      super::verdict(&page("You gave an answer too recently. You have 1m 5s left to wait.")),
+      Verdict::Wait(Some(Duration::from_secs(65)))
+    );
+    assert_eq!(super::verdict(&page("You don't seem to be solving the right level.")), Verdict::WrongLevel);
+    assert_eq!(super::verdict("<p>Something <b>else</b></p>"), Verdict::Unknown("Something else".to_string()));
+  }
+
+  // Serves the given responses, one per connection, and returns the requests
+  // that were received.
+  fn serve(responses: Vec<(u16, &'static str)>) -> (String, thread::JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = format!("http://{}", listener.local_addr().unwrap());
+
+    let server = thread::spawn(move || {
+      let mut requests = vec![];
+
+      for (status, body) in responses {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut request = String::new();
+        let mut length = 0;
+
+        loop {
+          let mut line = String::new();
+          reader.read_line(&mut line).unwrap();
+
+          if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+            length = value.trim().parse::<usize>().unwrap();
+          }
+
+          if line.trim().is_empty() {
+            break;
+          }
+
+          request.push_str(&line);
+        }
+
+        let mut body_bytes = vec![0; length];
+        reader.read_exact(&mut body_bytes).unwrap();
+        request.push_str(&String::from_utf8_lossy(&body_bytes));
+        requests.push(request);
+
+        let _ = write!(stream, "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len());
+      }
+
+      requests
+    });
+
+    (address, server)
+  }
+
+  #[test]
+  fn mock_server() {
+    let (address, server) = serve(vec![
+      (200, "...#\n.S..\n"),
+      (200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+      (404, "Not found"),
+    ]);
+
+    let stamp = env::temp_dir().join(format!("aoc-client-test-{}", std::process::id()));
+    let config = Config {
+      session: Some("cookie".to_string()),
+      base_url: Some(address),
+      interval: Some(0.0),
+    };
+
+    let client = Client::new(config, stamp.clone()).unwrap();
+
+    assert_eq!(client.input(2023, 21), Ok("...#\n.S..\n".to_string()));
+    assert_eq!(client.submit(2023, 21, 1, "42"), Ok(Verdict::TooLow));
+    assert!(client.input(2023, 26).unwrap_err().contains("not unlocked yet"));
+
+    let requests = server.join().unwrap();
+    let _ = std::fs::remove_file(stamp);
+
+    assert!(requests[0].starts_with("GET /2023/day/21/input HTTP/1.1"));
+    assert!(requests[0].contains("session=cookie"));
+    assert!(requests[1].starts_with("POST /2023/day/21/answer HTTP/1.1"));
+    assert!(requests[1].ends_with("level=1&answer=42"));
+  }
+}
@@ -0,0 +1,130 @@
+use std::any::Any;
+
+use crate::{
+  answer::Answer,
+  error::Result,
+  input::Input,
+  options::{Description, Options, Setting},
+};
+
+pub trait Day {
+  // Owned, as it outlives the `Input` it is parsed from.
+  type Input;
+  // Days without options use `()`.
+  type Options: Options;
+
+  fn parse(input: &Input) -> Result<Self::Input>;
+
+  // Whether the input has a shape that sets this day apart from the others
+  // that can parse it, such as a header, to tell which day an input is for.
+  fn fingerprint(_input: &Input) -> bool {
+    false
+  }
+
+  fn part1(input: &Self::Input, opts: &Self::Options) -> Answer;
+  fn part2(input: &Self::Input, opts: &Self::Options) -> Answer;
+}
+
+// Parsed inputs are shared between the parts of a day, which may be solved on
+// different threads.
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+// Options of a day, once its settings were applied.
+pub type Configured = Box<dyn Any + Send + Sync>;
+
+// Index of the setting that could not be applied, and what was expected.
+pub type Rejected = (usize, String);
+
+// Type-erased view over a `Day` implementation, so every day can live in the
+// same registry regardless of its parsed input type. Only the parts registered
+// with `#[aoc::solution]` are exposed.
+pub struct Solution {
+  pub year: usize,
+  pub day: usize,
+  parse: fn(&Input) -> Result<Parsed>,
+  configure: fn(&[&Setting]) -> std::result::Result<Configured, Rejected>,
+  describe: fn() -> Vec<Description>,
+  fingerprint: fn(&Input) -> bool,
+  parts: Vec<Part>,
+}
+
+struct Part {
+  part: usize,
+  name: Option<&'static str>,
+  solve: fn(&Parsed, &Configured) -> Answer,
+}
+
+impl Solution {
+  pub fn new<D>(year: usize, day: usize, parts: &[(usize, Option<&'static str>)]) -> Self
+  where
+    D: Day,
+    D::Input: Send + Sync + 'static,
+  {
+    let solvers: [fn(&Parsed, &Configured) -> Answer; 2] = [
+      |input, opts| D::part1(downcast(input, "parsed input"), downcast(opts, "options")),
+      |input, opts| D::part2(downcast(input, "parsed input"), downcast(opts, "options")),
+    ];
+
+    Solution {
+      year,
+      day,
+      parse: |input| Ok(Box::new(D::parse(input)?)),
+      configure: |settings| {
+        let mut options = D::Options::default();
+
+        for (index, setting) in settings.iter().enumerate() {
+          options.set(&setting.name, &setting.value).map_err(|expected| (index, expected))?;
+        }
+
+        Ok(Box::new(options))
+      },
+      describe: D::Options::describe,
+      fingerprint: D::fingerprint,
+      parts: parts.iter().map(|&(part, name)| Part { part, name, solve: solvers[part - 1] }).collect(),
+    }
+  }
+
+  pub fn parts(&self) -> Vec<usize> {
+    self.parts.iter().map(|part| part.part).collect()
+  }
+
+  pub fn has_part(&self, part: usize) -> bool {
+    self.parts.iter().any(|registered| registered.part == part)
+  }
+
+  pub fn name(&self, part: usize) -> Option<&'static str> {
+    self.parts.iter().find(|registered| registered.part == part)?.name
+  }
+
+  pub fn describe(&self) -> Vec<Description> {
+    (self.describe)()
+  }
+
+  // Applies the settings, in order, over the defaults of the options.
+  pub fn configure(&self, settings: &[&Setting]) -> std::result::Result<Configured, Rejected> {
+    (self.configure)(settings)
+  }
+
+  pub fn parse(&self, input: &Input) -> Result<Parsed> {
+    (self.parse)(input)
+  }
+
+  pub fn fingerprint(&self, input: &Input) -> bool {
+    (self.fingerprint)(input)
+  }
+
+  pub fn solve(&self, part: usize, input: &Parsed, opts: &Configured) -> Answer {
+    match self.parts.iter().find(|registered| registered.part == part) {
+      Some(registered) => (registered.solve)(input, opts),
+      None => panic!("{} day {} has no registered part {part}", self.year, self.day),
+    }
+  }
+}
+
+// Parsed inputs and configured options share the same type-erased box.
+fn downcast<'a, T>(value: &'a Parsed, what: &str) -> &'a T
+where
+  T: 'static,
+{
+  value.downcast_ref::<T>().unwrap_or_else(|| panic!("{what} does not belong to this day"))
+}
@@ -0,0 +1,93 @@
+use crate::{answer::Answer, day::Day, error::Result, input::Input};
+
+crate::tests!(Day01, 2023, 1, {
+  part1: [(example() => 142)],
+  part2: [
+    (example() => 281),
+    // Spelled out digits can share letters.
+    (inline("eightwo") => 82),
+    (inline("
+      oneight
+      sevenine
+    ") => 97),
+  ],
+});
+
+// Lines only spelling their digits out have none to calibrate with in the
+// first part.
+fn first_and_last_digit<S>(string: S) -> i64
+where
+  S: AsRef<str>,
+{
+  let mut digits = string.as_ref().chars().filter_map(|char| char.to_digit(10));
+
+  match digits.next() {
+    Some(first) => (first * 10 + digits.next_back().unwrap_or(first)).into(),
+    None => 0,
+  }
+}
+
+static LETTERS: &[(&str, &str)] = &[
+  ("one", "o1e"),
+  ("two", "t2o"),
+  ("six", "s6x"),
+  ("four", "f4r"),
+  ("five", "f5e"),
+  ("nine", "n9e"),
+  ("three", "t3e"),
+  ("seven", "s7n"),
+  ("eight", "e8t"),
+];
+
+fn replace_words_with_digits<S>(string: S) -> String
+where
+  S: AsRef<str>,
+{
+  LETTERS.iter().fold(string.as_ref().to_string(), |acc, (word, value)| acc.replace(word, value))
+}
+
+pub struct Day01;
+
+impl Day for Day01 {
+  type Input = Vec<String>;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    input
+      .lines()
+      .map(
+        |line| match line.text.contains(|char: char| char.is_ascii_digit()) || LETTERS.iter().any(|(word, _)| line.text.contains(word)) {
+          true => Ok(line.text.to_string()),
+          false => Err(line.error(line.text, "a digit")),
+        },
+      )
+      .collect()
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.bytes().all(|line| line.iter().all(u8::is_ascii_alphanumeric) && line.iter().any(u8::is_ascii_alphabetic))
+  }
+
+  #[aoc::solution(day = 1, part = 1)]
+  fn part1(lines: &Self::Input, _opts: &Self::Options) -> Answer {
+    lines.iter().map(first_and_last_digit).sum::<i64>().into()
+  }
+
+  #[aoc::solution(day = 1, part = 2)]
+  fn part2(lines: &Self::Input, _opts: &Self::Options) -> Answer {
+    lines.iter().map(replace_words_with_digits).map(first_and_last_digit).sum::<i64>().into()
+  }
+}
+
+#[cfg(test)]
+mod errors {
+  use super::Day01;
+  use crate::{day::Day, input::Input};
+
+  #[test]
+  fn no_digit() {
+    let error = Day01::parse(&Input::new("1abc2\nabc")).unwrap_err();
+
+    assert_eq!(error.to_string(), "<input>:2:1: expected a digit");
+  }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use aoc::FromInput;
+
+use crate::{answer::Answer, day::Day, error::Result, input::Input, util::FromInput};
+
+crate::tests!(Day02, 2023, 2, (8, 2286));
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, FromInput)]
+pub enum Color {
+  #[aoc(fmt = "red")]
+  Red,
+  #[aoc(fmt = "green")]
+  Green,
+  #[aoc(fmt = "blue")]
+  Blue,
+}
+
+impl Color {
+  const fn max(&self) -> i64 {
+    use Color::*;
+
+    match self {
+      Red => 12,
+      Green => 13,
+      Blue => 14,
+    }
+  }
+}
+
+#[derive(Debug, FromInput)]
+#[aoc(fmt = "{0} {1}")]
+pub struct Cube(i64, Color);
+
+#[derive(Debug, FromInput)]
+#[aoc(fmt = "Game {id}: {draws}")]
+pub struct Game {
+  id: i64,
+  #[aoc(sep = "; ", sep = ", ")]
+  draws: Vec<Vec<Cube>>,
+}
+
+type Score = HashMap<Color, i64>;
+
+fn overflow_maxes(colors: &Score) -> bool {
+  [Color::Red, Color::Green, Color::Blue].iter().any(|color| colors.get(color).unwrap_or(&0) > &color.max())
+}
+
+pub struct Day02;
+
+impl Day for Day02 {
+  type Input = Vec<Game>;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    Game::from_lines(input.lines())
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.text().starts_with("Game ")
+  }
+
+  #[aoc::solution(day = 2, part = 1)]
+  fn part1(games: &Self::Input, _opts: &Self::Options) -> Answer {
+    let score = games.iter().fold(0, |acc, Game { id, draws }| {
+      let colors = draws.iter().flatten().fold(Score::new(), |mut acc, Cube(count, color)| {
+        let current_count = acc.get(color).unwrap_or(&0);
+
+        if count > current_count {
+          acc.insert(*color, *count);
+        }
+
+        acc
+      });
+
+      if overflow_maxes(&colors) {
+        acc
+      } else {
+        acc + *id
+      }
+    });
+
+    score.into()
+  }
+
+  #[aoc::solution(day = 2, part = 2)]
+  fn part2(games: &Self::Input, _opts: &Self::Options) -> Answer {
+    let score = games.iter().fold(Vec::<i64>::new(), |mut acc, Game { draws, .. }| {
+      let colors = draws.iter().flatten().fold(Score::new(), |mut acc, Cube(count, color)| {
+        let current_count = acc.get(color).unwrap_or(&0);
+
+        if count > current_count {
+          acc.insert(*color, *count);
+        }
+
+        acc
+      });
+
+      acc.push(colors.values().product());
+      acc
+    });
+
+    score.into_iter().sum::<i64>().into()
+  }
+}
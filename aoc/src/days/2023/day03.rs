@@ -1,23 +1,28 @@
 use std::{cmp, collections::HashMap};
 
-use crate::util::{self, parse, Options};
+use crate::{
+  answer::Answer,
+  day::Day,
+  error::{Error, Result},
+  input::Input,
+};
 
-crate::tests!(3, (4361, 467835));
+crate::tests!(Day03, 2023, 3, (4361, 467835));
 
 type Grid = Vec<Vec<char>>;
 
 #[derive(Debug)]
-struct EnginePart {
+pub struct EnginePart {
   number: i64,
   row: usize,
   col: usize,
   length: usize,
 }
 
-fn get_coords_of_parts(input: &str) -> (Grid, Vec<EnginePart>) {
-  let lines: Vec<Vec<char>> = util::read_file_lines(input).into_iter().map(|line| line.chars().collect::<Vec<_>>()).collect();
+fn get_coords_of_parts(input: &Input) -> Result<(Grid, Vec<EnginePart>)> {
+  let lines: Grid = input.grid()?.cells(Ok)?;
 
-  let (_, cols) = (lines.len(), lines.get(0).unwrap().len());
+  let cols = lines[0].len();
   let mut parts: Vec<EnginePart> = vec![];
 
   for (row, chars) in lines.iter().enumerate() {
@@ -40,7 +45,7 @@ fn get_coords_of_parts(input: &str) -> (Grid, Vec<EnginePart>) {
 
       if !result.is_empty() {
         parts.push(EnginePart {
-          number: parse::<i64>(&result),
+          number: result.parse::<i64>().map_err(|_| Error::parse(row + 1, col + 1, result.len(), "`i64`"))?,
           row,
           col,
           length: result.len(),
@@ -51,7 +56,7 @@ fn get_coords_of_parts(input: &str) -> (Grid, Vec<EnginePart>) {
     }
   }
 
-  (lines, parts)
+  Ok((lines, parts))
 }
 
 const ADJACENCY_MATRIX: [(isize, isize); 8] = [(0, -1), (-1, -1), (-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1)];
@@ -104,24 +109,45 @@ fn find_adjacent_gears(grid: &Grid, part: &EnginePart) -> (Vec<(isize, isize)>,
   (gears, part.number)
 }
 
-pub fn part1(input: &str, _opts: Options) -> i64 {
-  let (grid, parts) = get_coords_of_parts(input);
+pub struct Day03;
 
-  parts.iter().filter(|part| is_part_adjacent(&grid, part)).map(|part| part.number).sum()
-}
+impl Day for Day03 {
+  type Input = (Grid, Vec<EnginePart>);
+  type Options = ();
 
-pub fn part2(input: &str, _opts: Options) -> i64 {
-  let (grid, parts) = get_coords_of_parts(input);
+  fn parse(input: &Input) -> Result<Self::Input> {
+    get_coords_of_parts(input)
+  }
 
-  let gears: Vec<(Vec<(isize, isize)>, i64)> = parts.iter().map(|part| find_adjacent_gears(&grid, part)).collect();
+  fn fingerprint(input: &Input) -> bool {
+    let Ok(grid) = input.grid() else { return false };
+    let mut tiles = grid.rows().flatten();
 
-  let mut gear_parts: HashMap<(isize, isize), Vec<i64>> = HashMap::new();
+    tiles.clone().any(u8::is_ascii_digit) && tiles.clone().any(|tile| tile.is_ascii_punctuation() && *tile != b'.') && tiles.all(|tile| tile.is_ascii_digit() || tile.is_ascii_punctuation())
+  }
 
-  for (gears_coords, part) in gears {
-    for gear in gears_coords {
-      gear_parts.entry(gear).or_default().push(part);
-    }
+  #[aoc::solution(day = 3, part = 1)]
+  fn part1((grid, parts): &Self::Input, _opts: &Self::Options) -> Answer {
+    parts.iter().filter(|part| is_part_adjacent(grid, part)).map(|part| part.number).sum::<i64>().into()
   }
 
-  gear_parts.into_iter().filter(|(_, parts)| parts.len() == 2).map(|(_, parts)| parts.iter().product::<i64>()).sum()
+  #[aoc::solution(day = 3, part = 2)]
+  fn part2((grid, parts): &Self::Input, _opts: &Self::Options) -> Answer {
+    let gears: Vec<(Vec<(isize, isize)>, i64)> = parts.iter().map(|part| find_adjacent_gears(grid, part)).collect();
+
+    let mut gear_parts: HashMap<(isize, isize), Vec<i64>> = HashMap::new();
+
+    for (gears_coords, part) in gears {
+      for gear in gears_coords {
+        gear_parts.entry(gear).or_default().push(part);
+      }
+    }
+
+    gear_parts
+      .into_iter()
+      .filter(|(_, parts)| parts.len() == 2)
+      .map(|(_, parts)| parts.iter().product::<i64>())
+      .sum::<i64>()
+      .into()
+  }
 }
@@ -0,0 +1,99 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{answer::Answer, day::Day, error::Result, input::Input};
+
+crate::tests!(Day04, 2023, 4, (13, 30));
+
+#[derive(Debug)]
+pub struct Card {
+  id: u64,
+  numbers: HashSet<i64>,
+  winners: HashSet<i64>,
+}
+
+fn parse_scratch_cards(input: &Input) -> Result<Vec<Card>> {
+  input
+    .lines()
+    .map(|line| {
+      let (id, numbers) = line.split_once(line.text, ": ")?;
+      let id = line.parse::<u64>(line.strip_prefix(id, "Card")?.trim_start())?;
+      let (winners, numbers) = line.split_once(numbers, " | ")?;
+
+      Ok(Card {
+        id,
+        numbers: numbers.split_whitespace().map(|number| line.parse::<i64>(number)).collect::<Result<_>>()?,
+        winners: winners.split_whitespace().map(|number| line.parse::<i64>(number)).collect::<Result<_>>()?,
+      })
+    })
+    .collect()
+}
+
+fn get_card_score(card: &Card) -> u64 {
+  card.numbers.intersection(&card.winners).count() as u64
+}
+
+type CardCache = HashMap<u64, i64>;
+
+fn scratch_cards(cards: &[Card], cache: &mut CardCache, from: usize, count: usize) -> i64 {
+  if count == 0 {
+    return 0;
+  }
+
+  cards[from..(from + count)]
+    .iter()
+    .enumerate()
+    .map(|(index, card)| {
+      let score = get_card_score(card);
+
+      let nested_score = match cache.get(&card.id) {
+        Some(cached) => *cached,
+
+        None => {
+          let score = scratch_cards(cards, cache, from + index + 1, score as usize);
+          cache.insert(card.id, score);
+
+          score
+        }
+      };
+
+      1 + nested_score
+    })
+    .sum()
+}
+
+pub struct Day04;
+
+impl Day for Day04 {
+  type Input = Vec<Card>;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_scratch_cards(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.text().starts_with("Card ")
+  }
+
+  #[aoc::solution(day = 4, part = 1)]
+  fn part1(cards: &Self::Input, _opts: &Self::Options) -> Answer {
+    cards
+      .iter()
+      .map(get_card_score)
+      .map(|score| {
+        (0..score).fold(0, |acc, _| match acc {
+          0 => 1,
+          n => n * 2,
+        })
+      })
+      .sum::<i64>()
+      .into()
+  }
+
+  #[aoc::solution(day = 4, part = 2)]
+  fn part2(cards: &Self::Input, _opts: &Self::Options) -> Answer {
+    let mut cache: CardCache = HashMap::new();
+
+    scratch_cards(cards, &mut cache, 0, cards.len()).into()
+  }
+}
@@ -0,0 +1,154 @@
+use std::{cmp, collections::HashMap, ops::Range};
+
+use itertools::Itertools;
+
+use crate::{
+  answer::Answer,
+  day::Day,
+  error::{Error, Result},
+  input::Input,
+};
+
+crate::tests!(Day05, 2023, 5, (35, 46));
+
+type Seeds = Vec<i64>;
+type ConversionBook = HashMap<String, Vec<ConversionOp>>;
+type ConversionOp = ((i64, i64), i64);
+
+fn parse_alamanac(input: &Input) -> Result<(Seeds, ConversionBook)> {
+  let mut seeds: Seeds = vec![];
+  let mut mappings: ConversionBook = HashMap::new();
+
+  let mut mapping: Option<&str> = None;
+
+  for line in input.lines() {
+    if line.text.is_empty() {
+      continue;
+    }
+
+    if let Some(seeds_list) = line.text.strip_prefix("seeds: ") {
+      seeds = seeds_list.split_whitespace().map(|seed| line.parse::<i64>(seed)).collect::<Result<_>>()?;
+
+      continue;
+    }
+
+    if let Some(conversion) = line.text.strip_suffix(" map:") {
+      mappings.insert(conversion.to_string(), vec![]);
+      mapping = Some(conversion);
+
+      continue;
+    }
+
+    let Some(mapping) = mapping else {
+      return Err(line.error(line.text, "`seeds: ` or a `map:` header"));
+    };
+
+    let (dest_start, src_start, length) = line
+      .text
+      .split_whitespace()
+      .map(|value| line.parse::<i64>(value))
+      .collect::<Result<Vec<_>>>()?
+      .into_iter()
+      .collect_tuple()
+      .ok_or_else(|| line.error(line.text, "three numbers"))?;
+
+    let entry = ((src_start, src_start + length), dest_start - src_start);
+
+    mappings.entry(mapping.to_string()).or_default().push(entry);
+  }
+
+  if let Some(step) = CONVERSION_STEPS.iter().find(|step| !mappings.contains_key(**step)) {
+    return Err(Error::end_of_input(input.text(), format!("`{step} map:`")));
+  }
+
+  Ok((seeds, mappings))
+}
+
+const CONVERSION_STEPS: [&str; 7] = [
+  "seed-to-soil",
+  "soil-to-fertilizer",
+  "fertilizer-to-water",
+  "water-to-light",
+  "light-to-temperature",
+  "temperature-to-humidity",
+  "humidity-to-location",
+];
+
+fn find_location_from_seeds(book: &ConversionBook, seeds: Vec<i64>) -> i64 {
+  seeds.into_iter().fold(i64::MAX, |min_location, seed| {
+    let location = CONVERSION_STEPS.into_iter().fold(seed, |value, step| {
+      let ops = book.get(step).unwrap();
+
+      match ops.iter().find(|((start, end), _)| (start..end).contains(&&value)) {
+        None => value,
+        Some((_, op)) => value + op,
+      }
+    });
+
+    cmp::min(location, min_location)
+  })
+}
+
+fn find_seed_from_location_and_step(book: &ConversionBook, upto: usize, seeds: &[Range<i64>], location: i64) -> Option<i64> {
+  let mut value = location;
+
+  'step: for step in CONVERSION_STEPS[0..upto].iter().rev() {
+    for ((start, end), op) in book.get(*step).unwrap().clone() {
+      if ((start + op)..(end + op)).contains(&value) {
+        value -= op;
+        continue 'step;
+      }
+    }
+  }
+
+  if seeds.iter().any(|range| range.contains(&(value))) {
+    Some(value)
+  } else {
+    None
+  }
+}
+
+pub struct Day05;
+
+impl Day for Day05 {
+  type Input = (Seeds, ConversionBook);
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_alamanac(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.text().starts_with("seeds:")
+  }
+
+  #[aoc::solution(day = 5, part = 1)]
+  fn part1((seeds, book): &Self::Input, _opts: &Self::Options) -> Answer {
+    find_location_from_seeds(book, seeds.clone()).into()
+  }
+
+  #[aoc::solution(day = 5, part = 2)]
+  fn part2((seeds, book): &Self::Input, _opts: &Self::Options) -> Answer {
+    let seeds = seeds
+      .chunks_exact(2)
+      .map(|value| value.iter().collect_tuple().unwrap())
+      .map(|(start, length)| *start..(*start + *length))
+      .collect::<Vec<_>>();
+
+    let all_steps_mins: Vec<_> = CONVERSION_STEPS
+      .into_iter()
+      .enumerate()
+      .flat_map(|(index, step)| {
+        book
+          .get(step)
+          .unwrap()
+          .iter()
+          .map(|((low, _), _p)| *low)
+          .filter_map(|loc| find_seed_from_location_and_step(book, index, &seeds, loc))
+          .collect::<Vec<_>>()
+      })
+      .collect();
+
+    find_location_from_seeds(book, all_steps_mins).into()
+  }
+}
@@ -0,0 +1,83 @@
+use crate::{
+  answer::Answer,
+  day::Day,
+  error::{Error, Result},
+  input::Input,
+  util::Line,
+};
+
+crate::tests!(Day06, 2023, 6, (288, 71503));
+
+type Races = Vec<(i64, i64)>;
+
+fn parse_values(line: Line, prefix: &str) -> Result<Vec<i64>> {
+  line.strip_prefix(line.text, prefix)?.split_whitespace().map(|value| line.parse::<i64>(value)).collect()
+}
+
+// Kerning the numbers back together yields the same digits as reading the
+// whole line as a single number, which may not fit where each of them did.
+fn parse_kerned(line: Line, prefix: &str) -> Result<i64> {
+  let values = line.strip_prefix(line.text, prefix)?.trim();
+
+  values.split_whitespace().collect::<String>().parse::<i64>().map_err(|_| line.error(values, "`i64`"))
+}
+
+// The races as listed, and as the single race of the second part.
+fn parse_races(input: &Input) -> Result<(Races, (i64, i64))> {
+  let mut races = input.lines();
+
+  let (Some(time_line), Some(distance_line)) = (races.next(), races.next()) else {
+    return Err(Error::end_of_input(input.text(), "`Time:` and `Distance:` lines"));
+  };
+
+  let times = parse_values(time_line, "Time:")?;
+  let distances = parse_values(distance_line, "Distance:")?;
+
+  if times.len() != distances.len() {
+    return Err(distance_line.error(distance_line.text, format!("{} distances", times.len())));
+  }
+
+  let kerned = (parse_kerned(time_line, "Time:")?, parse_kerned(distance_line, "Distance:")?);
+
+  Ok((times.into_iter().zip(distances).collect(), kerned))
+}
+
+fn compute_winning_combinations(races: &[(i64, i64)]) -> i64 {
+  races
+    .iter()
+    .map(|(time, distance)| {
+      let time = *time as f64;
+      let distance = *distance as f64;
+
+      let high = (time + (time.powi(2) - (4.0 * distance)).sqrt()) / 2.0;
+      let low = (time - (time.powi(2) - (4.0 * distance)).sqrt()) / 2.0;
+
+      ((high.floor() - low.ceil()) + (if high.fract() + low.fract() == 0.0 { -1.0 } else { 1.0 })) as i64
+    })
+    .product()
+}
+
+pub struct Day06;
+
+impl Day for Day06 {
+  type Input = (Races, (i64, i64));
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_races(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.text().starts_with("Time:") && input.lines().nth(1).is_some_and(|line| line.text.starts_with("Distance:"))
+  }
+
+  #[aoc::solution(day = 6, part = 1)]
+  fn part1((races, _): &Self::Input, _opts: &Self::Options) -> Answer {
+    compute_winning_combinations(races).into()
+  }
+
+  #[aoc::solution(day = 6, part = 2)]
+  fn part2((_, race): &Self::Input, _opts: &Self::Options) -> Answer {
+    compute_winning_combinations(&[*race]).into()
+  }
+}
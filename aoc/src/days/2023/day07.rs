@@ -2,15 +2,15 @@ use std::cmp::Ordering;
 
 use itertools::Itertools;
 
-use crate::util::{self, parse, Options};
+use crate::{answer::Answer, day::Day, error::Result, input::Input};
 
-crate::tests!(7, (6440, 5905));
+crate::tests!(Day07, 2023, 7, (6440, 5905));
 
 #[derive(Debug, Eq, PartialEq)]
 struct Hand(i64, i64, i64);
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-enum Suit {
+pub enum Suit {
   Value(usize),
   Jack,
   Queen,
@@ -34,18 +34,20 @@ impl From<Suit> for usize {
   }
 }
 
-impl From<char> for Suit {
-  fn from(value: char) -> Self {
+impl TryFrom<char> for Suit {
+  type Error = &'static str;
+
+  fn try_from(value: char) -> Result<Self, Self::Error> {
     use Suit::*;
 
     match value {
-      face @ '2'..='9' => Value(parse::<usize>(face.to_string().as_str())),
-      'T' => Value(10),
-      'J' => Jack,
-      'Q' => Queen,
-      'K' => King,
-      'A' => Ace,
-      _ => panic!("unknown card"),
+      face @ '2'..='9' => Ok(Value(face as usize - '0' as usize)),
+      'T' => Ok(Value(10)),
+      'J' => Ok(Jack),
+      'Q' => Ok(Queen),
+      'K' => Ok(King),
+      'A' => Ok(Ace),
+      _ => Err("a card"),
     }
   }
 }
@@ -119,34 +121,54 @@ fn compute_gains(hands: &[Hand]) -> i64 {
   hands.iter().sorted().enumerate().map(|(index, Hand(_, _, bid))| bid * (index as i64 + 1)).sum()
 }
 
-fn parse_hands(input: &str, jokers: bool) -> Vec<Hand> {
-  util::read_file_lines(input)
-    .into_iter()
-    .flat_map(|hand| {
-      hand
-        .split_once(' ')
-        .map(|(cards, bid)| (cards.chars().map(Suit::from).collect::<Vec<_>>(), parse::<i64>(bid)))
-        .map(|(cards, bid)| {
-          let value = raw_value(&cards, jokers);
-
-          (cards, value, bid)
-        })
-        .map(|(cards, value, bid)| match jokers {
-          false => Hand(hand_value(&build_suit_map(&cards)), value as i64, bid),
-          true => Hand(hand_value(&build_suit_map(&find_best_joker_replacement(&cards))), value as i64, bid),
-        })
+type Bids = Vec<(Vec<Suit>, i64)>;
+
+fn parse_hands(input: &Input) -> Result<Bids> {
+  input
+    .lines()
+    .map(|line| {
+      let (cards, bid) = line.split_once(line.text, " ")?;
+
+      Ok((line.cells(cards, Suit::try_from)?, line.parse::<i64>(bid)?))
     })
-    .collect::<Vec<_>>()
+    .collect()
 }
 
-pub fn part1(input: &str, _opts: Options) -> i64 {
-  let hands = parse_hands(input, false);
+fn score_hands(bids: &Bids, jokers: bool) -> Vec<Hand> {
+  bids
+    .iter()
+    .map(|(cards, bid)| {
+      let value = raw_value(cards, jokers);
 
-  compute_gains(&hands)
+      match jokers {
+        false => Hand(hand_value(&build_suit_map(cards)), value as i64, *bid),
+        true => Hand(hand_value(&build_suit_map(&find_best_joker_replacement(cards))), value as i64, *bid),
+      }
+    })
+    .collect::<Vec<_>>()
 }
 
-pub fn part2(input: &str, _opts: Options) -> i64 {
-  let hands = parse_hands(input, true);
+pub struct Day07;
+
+impl Day for Day07 {
+  type Input = Bids;
+  type Options = ();
 
-  compute_gains(&hands)
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_hands(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.lines().all(|line| line.text.split_once(' ').is_some_and(|(cards, _)| cards.len() == 5))
+  }
+
+  #[aoc::solution(day = 7, part = 1)]
+  fn part1(bids: &Self::Input, _opts: &Self::Options) -> Answer {
+    compute_gains(&score_hands(bids, false)).into()
+  }
+
+  #[aoc::solution(day = 7, part = 2)]
+  fn part2(bids: &Self::Input, _opts: &Self::Options) -> Answer {
+    compute_gains(&score_hands(bids, true)).into()
+  }
 }
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use aoc::FromInput;
+use itertools::{FoldWhile::*, Itertools};
+use num::integer::lcm;
+
+use crate::{
+  answer::Answer,
+  day::Day,
+  error::{Error, Result},
+  input::Input,
+  util::FromInput,
+};
+
+crate::tests!(Day08, 2023, 8, (6, 6));
+
+type Turns = Vec<char>;
+type Map = HashMap<String, (String, String)>;
+
+#[derive(FromInput)]
+#[aoc(fmt = "{at} = ({left}, {right})")]
+struct Step<'a> {
+  at: &'a str,
+  left: &'a str,
+  right: &'a str,
+}
+
+fn parse_steps(input: &Input) -> Result<(Turns, Map)> {
+  let mut lines = input.lines();
+
+  let turns = lines.next().ok_or_else(|| Error::end_of_input(input.text(), "a list of turns"))?;
+  let turns = turns.cells(turns.text, |turn| match turn {
+    'L' | 'R' => Ok(turn),
+    _ => Err("`L` or `R`"),
+  })?;
+
+  let steps = lines
+    .filter(|line| !line.text.is_empty())
+    .map(|line| Ok((Step::from_input(&line, line.text)?, line)))
+    .collect::<Result<Vec<_>>>()?;
+
+  let map = steps.iter().map(|(step, _)| (step.at.to_string(), (step.left.to_string(), step.right.to_string()))).collect::<Map>();
+
+  for (step, line) in &steps {
+    if let Some(unknown) = [step.left, step.right].into_iter().find(|node| !map.contains_key(*node)) {
+      return Err(line.error(unknown, "a known node"));
+    }
+  }
+
+  Ok((turns, map))
+}
+
+fn next_step(map: &Map, at: &str, to: &char) -> String {
+  let next = map.get(at).unwrap();
+
+  let next = match to {
+    'L' => &next.0,
+    'R' => &next.1,
+    _ => panic!("not a valid direction"),
+  };
+
+  next.to_string()
+}
+
+pub struct Day08;
+
+impl Day for Day08 {
+  type Input = (Turns, Map);
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_steps(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    let mut lines = input.lines();
+
+    lines.next().is_some_and(|turns| turns.text.bytes().all(|turn| turn == b'L' || turn == b'R')) && lines.nth(1).is_some_and(|node| node.text.contains(" = ("))
+  }
+
+  #[aoc::solution(day = 8, part = 1)]
+  fn part1((turns, map): &Self::Input, _opts: &Self::Options) -> Answer {
+    let (_, index): (_, i64) = turns
+      .iter()
+      .cycle()
+      .fold_while(("AAA".to_string(), 0), |(at, index), to| match at.as_str() {
+        "ZZZ" => Done((at, index)),
+        _ => Continue((next_step(map, &at, to), index + 1)),
+      })
+      .into_inner();
+
+    index.into()
+  }
+
+  #[aoc::solution(day = 8, part = 2)]
+  fn part2((turns, map): &Self::Input, _opts: &Self::Options) -> Answer {
+    map
+      .keys()
+      .filter_map(|start| match start.ends_with('A') {
+        false => None,
+        true => {
+          let index = turns
+            .iter()
+            .cycle()
+            .fold_while((start.to_string(), 0), |(at, index), to| match at.ends_with('Z') {
+              true => Done((at, index)),
+              false => Continue((next_step(map, &at, to), index + 1)),
+            })
+            .into_inner();
+
+          Some(index)
+        }
+      })
+      .map(|(_, index)| index as i64)
+      .fold(1, lcm)
+      .into()
+  }
+}
@@ -0,0 +1,72 @@
+use itertools::Itertools;
+
+use crate::{answer::Answer, day::Day, error::Result, input::Input};
+
+crate::tests!(Day09, 2023, 9, (114, 2));
+
+fn parse_predictions(input: &Input) -> Result<Vec<Vec<i64>>> {
+  input
+    .lines()
+    .map(|line| match line.text.trim().is_empty() {
+      true => Err(line.error(line.text, "a series of numbers")),
+      false => line.text.split_ascii_whitespace().map(|value| line.parse::<i64>(value)).collect(),
+    })
+    .collect()
+}
+
+fn compute_next_value(predictions: Vec<Vec<i64>>) -> i64 {
+  let sum = predictions.iter().fold(0, |acc, series| {
+    let mut value = series.clone();
+    let mut adds = *value.last().unwrap();
+
+    while !value.iter().all_equal() {
+      let new = value.iter().tuple_windows().fold(vec![], |mut acc, (left, right)| {
+        acc.push(right - left);
+        acc
+      });
+
+      adds += new.last().unwrap();
+      value = new;
+    }
+
+    acc + adds
+  });
+
+  sum
+}
+
+pub struct Day09;
+
+impl Day for Day09 {
+  type Input = Vec<Vec<i64>>;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_predictions(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input
+      .lines()
+      .all(|line| line.text.split_whitespace().count() > 1 && line.ints::<i64>().count() == line.text.split_whitespace().count())
+  }
+
+  #[aoc::solution(day = 9, part = 1)]
+  fn part1(predictions: &Self::Input, _opts: &Self::Options) -> Answer {
+    compute_next_value(predictions.clone()).into()
+  }
+
+  #[aoc::solution(day = 9, part = 2)]
+  fn part2(predictions: &Self::Input, _opts: &Self::Options) -> Answer {
+    let predictions = predictions
+      .iter()
+      .cloned()
+      .map(|mut series| {
+        series.reverse();
+        series
+      })
+      .collect::<Vec<_>>();
+
+    compute_next_value(predictions).into()
+  }
+}
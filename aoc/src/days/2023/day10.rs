@@ -3,32 +3,33 @@ use std::{
   ops::Neg,
 };
 
-use crate::util::{self, Options};
+use crate::{
+  answer::Answer,
+  day::Day,
+  error::{Error, Result},
+  input::Input,
+};
 
 type Maze = HashMap<Coord, Directions>;
 type Coord = (usize, usize);
 
-crate::tests!(10, (8, 10));
+crate::tests!(Day10, 2023, 10, (8, 10));
 
-fn parse_maze(input: &str) -> (Maze, Coord) {
-  let input = util::read_file_lines(input);
+fn parse_maze(input: &Input) -> Result<(Maze, Coord)> {
   let mut maze: Maze = HashMap::new();
-  let mut start: Option<Coord> = None;
+  let grid = input.grid()?;
 
-  for (row, cols) in input.iter().enumerate() {
-    for (col, symbol) in cols.chars().enumerate() {
+  for (row, cols) in grid.cells(Ok)?.into_iter().enumerate() {
+    for (col, symbol) in cols.into_iter().enumerate() {
       if symbol == '.' {
         continue;
       }
-      if symbol == 'S' {
-        start = Some((col, row));
-      }
 
-      maze.insert((col, row), symbol.into());
+      maze.insert((col, row), symbol.try_into().map_err(|expected| Error::parse(row + 1, col + 1, 1, expected))?);
     }
   }
 
-  (maze, start.unwrap())
+  Ok((maze, grid.position(b'S').ok_or_else(|| Error::end_of_input(input.text(), "a starting tile `S`"))?))
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -70,21 +71,23 @@ impl Direction {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Directions(HashSet<Direction>);
+pub struct Directions(HashSet<Direction>);
+
+impl TryFrom<char> for Directions {
+  type Error = &'static str;
 
-impl From<char> for Directions {
-  fn from(value: char) -> Self {
+  fn try_from(value: char) -> Result<Self, Self::Error> {
     use Direction::*;
 
     match value {
-      'S' => Directions(HashSet::from_iter([North, West, East, South])),
-      '|' => Directions(HashSet::from_iter([North, South])),
-      '-' => Directions(HashSet::from_iter([West, East])),
-      'L' => Directions(HashSet::from_iter([North, East])),
-      '7' => Directions(HashSet::from_iter([West, South])),
-      'J' => Directions(HashSet::from_iter([North, West])),
-      'F' => Directions(HashSet::from_iter([East, South])),
-      _ => Directions(HashSet::new()),
+      'S' => Ok(Directions(HashSet::from_iter([North, West, East, South]))),
+      '|' => Ok(Directions(HashSet::from_iter([North, South]))),
+      '-' => Ok(Directions(HashSet::from_iter([West, East]))),
+      'L' => Ok(Directions(HashSet::from_iter([North, East]))),
+      '7' => Ok(Directions(HashSet::from_iter([West, South]))),
+      'J' => Ok(Directions(HashSet::from_iter([North, West]))),
+      'F' => Ok(Directions(HashSet::from_iter([East, South]))),
+      _ => Err("a pipe"),
     }
   }
 }
@@ -147,18 +150,35 @@ fn walk_the_maze(maze: &Maze, start: &Coord, mut at: Coord, mut previous: Direct
   (distance / 2, (area as i64 / 2).abs() - distance / 2 + 1)
 }
 
-pub fn part1(input: &str, _opts: Options) -> i64 {
-  let (maze, start) = parse_maze(input);
-  let (direction, at) = first_tile(&maze, start);
-  let (distance, _) = walk_the_maze(&maze, &start, at, direction);
+pub struct Day10;
 
-  distance
-}
+impl Day for Day10 {
+  type Input = (Maze, Coord);
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_maze(input)
+  }
 
-pub fn part2(input: &str, _opts: Options) -> i64 {
-  let (maze, start) = parse_maze(input);
-  let (direction, at) = first_tile(&maze, start);
-  let (_, area) = walk_the_maze(&maze, &start, at, direction);
+  fn fingerprint(input: &Input) -> bool {
+    input
+      .grid()
+      .is_ok_and(|grid| grid.position(b'S').is_some() && grid.rows().flatten().all(|tile| b"|-LJ7F.S".contains(tile)))
+  }
+
+  #[aoc::solution(day = 10, part = 1)]
+  fn part1((maze, start): &Self::Input, _opts: &Self::Options) -> Answer {
+    let (direction, at) = first_tile(maze, *start);
+    let (distance, _) = walk_the_maze(maze, start, at, direction);
+
+    distance.into()
+  }
+
+  #[aoc::solution(day = 10, part = 2)]
+  fn part2((maze, start): &Self::Input, _opts: &Self::Options) -> Answer {
+    let (direction, at) = first_tile(maze, *start);
+    let (_, area) = walk_the_maze(maze, start, at, direction);
 
-  area
+    area.into()
+  }
 }
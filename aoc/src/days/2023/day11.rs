@@ -0,0 +1,99 @@
+use itertools::Itertools;
+
+use crate::{
+  answer::Answer,
+  day::Day,
+  error::{Error, Result},
+  input::Input,
+};
+
+crate::tests!(Day11, 2023, 11, (374, 82000210));
+
+type Coord = (usize, usize);
+type Universe = Vec<Vec<char>>;
+
+fn compute_universe_expansion(universe: &[Vec<char>], factor: usize) -> (Vec<usize>, Vec<usize>) {
+  let mut col_offsets: Vec<usize> = vec![];
+  let mut row_offsets: Vec<usize> = vec![];
+
+  for row_id in 0..universe.len() {
+    let new = col_offsets.last().unwrap_or(&0);
+
+    match universe.get(row_id).unwrap().iter().all_equal_value() {
+      Ok('.') => col_offsets.push(new + factor - 1),
+      _ => col_offsets.push(*new),
+    }
+
+    let new = row_offsets.last().unwrap_or(&0);
+    let mut col = universe.iter().map(|row| row.get(row_id).unwrap());
+
+    match col.all_equal_value() {
+      Ok('.') => row_offsets.push(new + factor - 1),
+      _ => row_offsets.push(*new),
+    }
+  }
+
+  (col_offsets, row_offsets)
+}
+
+fn parse_universe(input: &Input) -> Result<Universe> {
+  let universe = input.grid()?.cells(|symbol| match symbol {
+    '.' | '#' => Ok(symbol),
+    _ => Err("`.` or `#`"),
+  })?;
+
+  // The expansion is computed on rows and columns at the same time.
+  match universe.len() == universe[0].len() {
+    true => Ok(universe),
+    false => Err(Error::end_of_input(input.text(), format!("{} rows in a square universe", universe[0].len()))),
+  }
+}
+
+fn map_universe(universe: &Universe, expansion_factor: usize) -> Vec<Coord> {
+  let mut galaxies: Vec<Coord> = vec![];
+
+  let (col_offsets, row_offsets) = compute_universe_expansion(universe, expansion_factor);
+
+  for (x, row) in universe.iter().enumerate() {
+    for (y, symbol) in row.iter().enumerate() {
+      if symbol == &'#' {
+        galaxies.push((x + col_offsets.get(x).unwrap(), y + row_offsets.get(y).unwrap()));
+      }
+    }
+  }
+
+  galaxies
+}
+
+fn manhattan_distance_of_life_the_universe_and_everything(galaxies: &[Coord]) -> u64 {
+  galaxies.iter().tuple_combinations().map(|((x1, y1), (x2, y2))| (x1.abs_diff(*x2) + y1.abs_diff(*y2)) as u64).sum()
+}
+
+pub struct Day11;
+
+impl Day for Day11 {
+  type Input = Universe;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_universe(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.lines().all(|line| !line.text.is_empty() && line.text.bytes().all(|tile| tile == b'#' || tile == b'.'))
+  }
+
+  #[aoc::solution(day = 11, part = 1)]
+  fn part1(universe: &Self::Input, _opts: &Self::Options) -> Answer {
+    let galaxies = map_universe(universe, 2);
+
+    manhattan_distance_of_life_the_universe_and_everything(&galaxies).into()
+  }
+
+  #[aoc::solution(day = 11, part = 2)]
+  fn part2(universe: &Self::Input, _opts: &Self::Options) -> Answer {
+    let galaxies = map_universe(universe, 1_000_000);
+
+    manhattan_distance_of_life_the_universe_and_everything(&galaxies).into()
+  }
+}
@@ -0,0 +1,120 @@
+use std::iter::repeat_n;
+
+use itertools::intersperse;
+
+use crate::{answer::Answer, day::Day, error::Result, input::Input};
+
+crate::tests!(Day12, 2023, 12, (21, 525152));
+
+const CACHE_SIZE: usize = 1 << 12;
+
+type Cache<'a> = [i64; CACHE_SIZE];
+
+type Record = (Vec<char>, Vec<u8>);
+
+fn parse_springs(input: &Input) -> Result<Vec<Record>> {
+  input
+    .lines()
+    .map(|line| {
+      let (springs, counts) = line.split_once(line.text, " ")?;
+
+      let springs = line.cells(springs, |spring| match spring {
+        '.' | '#' | '?' => Ok(spring),
+        _ => Err("one of `.`, `#` or `?`"),
+      })?;
+
+      Ok((springs, counts.split(',').map(|count| line.parse::<u8>(count)).collect::<Result<Vec<_>>>()?))
+    })
+    .collect()
+}
+
+fn unfold((springs, counts): &Record, folds: usize) -> Record {
+  (
+    intersperse(repeat_n(springs.as_slice(), folds), &['?'][..]).flatten().copied().collect::<Vec<_>>(),
+    repeat_n(counts, folds).flatten().copied().collect::<Vec<_>>(),
+  )
+}
+
+fn count_arrangements(records: &[Record]) -> i64 {
+  let mut cache: Cache = [i64::MAX; CACHE_SIZE];
+
+  records
+    .iter()
+    .map(|(row, broken)| {
+      cache.fill(i64::MAX);
+
+      arrangements(&mut cache, row, broken)
+    })
+    .sum()
+}
+
+fn cache_key(row: &[char], matches: &[u8]) -> usize {
+  (row.len() << 5) | matches.len()
+}
+
+fn arrangements<'a>(cache: &mut Cache<'a>, row: &'a [char], matches: &'a [u8]) -> i64 {
+  match cache[cache_key(row, matches)] {
+    i64::MAX => {}
+    memoized => return memoized,
+  }
+
+  if let ['.', rest @ ..] = row {
+    return arrangements(cache, rest, matches);
+  }
+
+  if row.is_empty() {
+    return match matches {
+      [] => 1,
+      _ => 0,
+    };
+  }
+
+  if matches.is_empty() {
+    return match row.contains(&'#') {
+      true => 0,
+      false => 1,
+    };
+  }
+
+  let mut result = 0;
+
+  let needle = matches[0] as usize;
+  let symbol = row[0];
+
+  if symbol == '?' {
+    result += arrangements(cache, &row[1..], matches);
+  }
+
+  if ['#', '?'].contains(&symbol) && needle <= row.len() && !row[..needle].contains(&'.') && (needle == row.len() || row[needle] != '#') {
+    result += arrangements(cache, row.get(needle + 1..).unwrap_or(&[]), &matches[1..])
+  }
+
+  cache[cache_key(row, matches)] = result;
+
+  result
+}
+
+pub struct Day12;
+
+impl Day for Day12 {
+  type Input = Vec<Record>;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_springs(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.lines().next().is_some_and(|line| line.text.contains('?'))
+  }
+
+  #[aoc::solution(day = 12, part = 1)]
+  fn part1(records: &Self::Input, _opts: &Self::Options) -> Answer {
+    count_arrangements(records).into()
+  }
+
+  #[aoc::solution(day = 12, part = 2)]
+  fn part2(records: &Self::Input, _opts: &Self::Options) -> Answer {
+    count_arrangements(&records.iter().map(|record| unfold(record, 5)).collect::<Vec<_>>()).into()
+  }
+}
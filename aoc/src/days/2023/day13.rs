@@ -0,0 +1,90 @@
+use crate::{answer::Answer, day::Day, error::Result, input::Input, util::transpose};
+
+crate::tests!(Day13, 2023, 13, (405, 400));
+
+type Grid = Vec<Vec<bool>>;
+
+fn parse_field(input: &Input) -> Result<Vec<Grid>> {
+  input
+    .blocks()
+    .map(|block| {
+      block.grid()?.cells(|symbol| match symbol {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err("`#` or `.`"),
+      })
+    })
+    .collect()
+}
+
+fn find_mirror_point(field: &Grid, max: usize) -> i64 {
+  for i in 0..field.len() {
+    if i >= field.len() - 1 {
+      break;
+    }
+
+    let root_differences = find_difference_count(&field[i], &field[i + 1]);
+
+    if root_differences <= max {
+      let mut left = i as isize - 1;
+      let mut right = i as isize + 2;
+      let mut differences = 0;
+
+      loop {
+        if left < 0 || right > field.len() as isize - 1 {
+          if (root_differences + differences) == max {
+            return i as i64 + 1;
+          }
+
+          break;
+        }
+
+        differences += find_difference_count(&field[left as usize], &field[right as usize]);
+
+        left -= 1;
+        right += 1;
+      }
+    }
+  }
+
+  0
+}
+
+fn find_difference_count(a: &[bool], b: &[bool]) -> usize {
+  a.iter().enumerate().filter(|(index, left)| b.get(*index).unwrap() != *left).count()
+}
+
+pub struct Day13;
+
+impl Day for Day13 {
+  type Input = Vec<Grid>;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_field(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.blocks().nth(1).is_some() && input.bytes().flatten().all(|tile| matches!(tile, b'#' | b'.'))
+  }
+
+  #[aoc::solution(day = 13, part = 1)]
+  fn part1(fields: &Self::Input, _opts: &Self::Options) -> Answer {
+    fields
+      .iter()
+      .cloned()
+      .map(|field| (100 * find_mirror_point(&field, 0)) + find_mirror_point(&transpose(field), 0))
+      .sum::<i64>()
+      .into()
+  }
+
+  #[aoc::solution(day = 13, part = 2)]
+  fn part2(fields: &Self::Input, _opts: &Self::Options) -> Answer {
+    fields
+      .iter()
+      .cloned()
+      .map(|field| (100 * find_mirror_point(&field, 1)) + find_mirror_point(&transpose(field), 1))
+      .sum::<i64>()
+      .into()
+  }
+}
@@ -0,0 +1,116 @@
+use crate::{
+  answer::Answer,
+  day::Day,
+  error::Result,
+  input::Input,
+  util::{trace, transpose},
+};
+
+crate::tests!(Day14, 2023, 14, (136, 64));
+
+type Grid = Vec<Vec<char>>;
+
+fn parse_platform(input: &Input) -> Result<Grid> {
+  input.grid()?.cells(|symbol| match symbol {
+    'O' | '#' | '.' => Ok(symbol),
+    _ => Err("one of `O`, `#` or `.`"),
+  })
+}
+
+fn rotate(matrix: Grid) -> Grid {
+  let mut matrix = transpose(matrix);
+
+  matrix.iter_mut().for_each(|row| row.reverse());
+  matrix
+}
+
+fn tilt(mut platform: Grid) -> Grid {
+  for col in 0..platform[0].len() {
+    let mut min = 0;
+
+    for row in 0..platform.len() {
+      match platform[row][col] {
+        '#' => min = row + 1,
+        'O' => {
+          if row > min {
+            platform[min][col] = 'O';
+            platform[row][col] = '.';
+          }
+
+          min += 1;
+        }
+        '.' => {}
+        _ => panic!("unexpected character"),
+      }
+    }
+  }
+
+  platform
+}
+
+fn count(platform: &Grid) -> i64 {
+  let mut score = 0;
+
+  for col in 0..platform[0].len() {
+    for (index, row) in (0..platform.len()).enumerate() {
+      if platform[row][col] == 'O' {
+        score += platform.len() - index;
+      }
+    }
+  }
+
+  score as i64
+}
+
+pub struct Day14;
+
+impl Day for Day14 {
+  type Input = Grid;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_platform(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.text().contains('O')
+  }
+
+  #[aoc::solution(day = 14, part = 1)]
+  fn part1(platform: &Self::Input, _opts: &Self::Options) -> Answer {
+    count(&tilt(platform.clone())).into()
+  }
+
+  #[aoc::solution(day = 14, part = 2)]
+  fn part2(platform: &Self::Input, _opts: &Self::Options) -> Answer {
+    let max = 1_000_000_000;
+    let mut platform = platform.clone();
+    let mut cycle = 0;
+
+    let mut seen: Vec<Grid> = Vec::new();
+    let mut progress = trace::progress("day 14 spin cycles", Some(max as u64));
+
+    while cycle < max {
+      progress.tick(cycle as u64);
+
+      for _ in 0..4 {
+        platform = rotate(tilt(platform));
+      }
+
+      if let Some(memoized) = seen.iter().position(|saved| saved == &platform) {
+        let seen = &seen[memoized..];
+
+        trace::counter("day 14 loop start", memoized as u64);
+        trace::counter("day 14 loop length", seen.len() as u64);
+
+        return count(&seen[(max - cycle - 1) % seen.len()]).into();
+      }
+
+      seen.push(platform.clone());
+
+      cycle += 1;
+    }
+
+    panic!("should not be reached");
+  }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use crate::{
+  answer::Answer,
+  day::Day,
+  error::{Error, Result},
+  input::Input,
+};
+
+crate::tests!(Day15, 2023, 15, (1320, 145));
+
+// Steps can wrap over several lines, so offsets within the joined sequence
+// are mapped back to the input lines to report errors.
+fn locate(input: &Input, mut offset: usize) -> (usize, usize) {
+  for line in input.lines() {
+    if offset < line.text.len() {
+      return (line.number, offset + 1);
+    }
+
+    offset -= line.text.len();
+  }
+
+  input.lines().last().map_or((1, 1), |line| (line.number, line.text.len() + 1))
+}
+
+// The whole step is hashed in the first part, what it does to the boxes matters
+// in the second.
+#[derive(Debug)]
+pub struct Step {
+  item: String,
+  label: String,
+  op: Op,
+}
+
+fn parse_sequence(input: &Input) -> Result<Vec<Step>> {
+  let sequence = input.lines().map(|line| line.text).collect::<String>();
+  let mut offset = 0;
+  let mut steps = Vec::new();
+
+  for item in sequence.split(',') {
+    let step = match (item.split_once('='), item.split_once('-')) {
+      (Some((label, value)), _) => match value.parse::<i64>() {
+        Ok(power) => Ok((label, Op::Set(power))),
+        Err(_) => Err((offset + label.len() + 1, value.len(), "`i64`")),
+      },
+      (None, Some((label, _))) => Ok((label, Op::Remove)),
+      (None, None) => Err((offset, item.len(), "`=` or `-`")),
+    };
+
+    match step {
+      Ok((label, op)) => steps.push(Step {
+        item: item.to_string(),
+        label: label.to_string(),
+        op,
+      }),
+
+      Err((at, span, expected)) => {
+        let (line, column) = locate(input, at);
+
+        return Err(Error::parse(line, column, span, expected));
+      }
+    }
+
+    offset += item.len() + 1;
+  }
+
+  Ok(steps)
+}
+
+fn hash<S>(item: S) -> i64
+where
+  S: AsRef<str>,
+{
+  item.as_ref().chars().fold(0, |acc, item| {
+    let code = item as i64;
+
+    ((acc + code) * 17) % 256
+  })
+}
+
+fn verification_number(steps: &[Step]) -> i64 {
+  steps.iter().map(|step| hash(&step.item)).sum()
+}
+
+#[derive(Debug)]
+pub enum Op {
+  Set(i64),
+  Remove,
+}
+
+const fn box_refraction_factor(box_id: i64, index: usize, power: i64) -> i64 {
+  (box_id + 1) * (index as i64 + 1) * power
+}
+
+fn little_boxes_on_the_hillside(steps: &[Step]) -> i64 {
+  let mut boxes: HashMap<i64, Vec<(String, i64)>> = HashMap::new();
+
+  for Step { label, op, .. } in steps {
+    let box_id = hash(label);
+
+    match *op {
+      Op::Set(value) => {
+        let slot = boxes.entry(box_id).or_default();
+
+        if let Some(index) = slot.iter().position(|(l, _)| label == l) {
+          slot[index] = (label.to_string(), value);
+        } else {
+          slot.push((label.to_string(), value));
+        }
+      }
+
+      Op::Remove => {
+        if let Some(slot) = boxes.get_mut(&box_id) {
+          if let Some(index) = slot.iter().position(|(l, _)| label == l) {
+            slot.remove(index);
+          }
+        };
+      }
+    }
+  }
+
+  boxes
+    .into_iter()
+    .flat_map(|(box_id, steps)| steps.into_iter().enumerate().map(move |(index, (_, power))| box_refraction_factor(box_id, index, power)))
+    .sum()
+}
+
+pub struct Day15;
+
+impl Day for Day15 {
+  type Input = Vec<Step>;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_sequence(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.text().contains(',') && !input.text().contains(' ')
+  }
+
+  #[aoc::solution(day = 15, part = 1)]
+  fn part1(sequence: &Self::Input, _opts: &Self::Options) -> Answer {
+    verification_number(sequence).into()
+  }
+
+  #[aoc::solution(day = 15, part = 2)]
+  fn part2(sequence: &Self::Input, _opts: &Self::Options) -> Answer {
+    little_boxes_on_the_hillside(sequence).into()
+  }
+}
+
+#[cfg(test)]
+mod errors {
+  use super::Day15;
+  use crate::{day::Day, input::Input};
+
+  #[test]
+  fn empty_input() {
+    let error = Day15::parse(&Input::new("")).unwrap_err();
+
+    assert_eq!(error.to_string(), "<input>:1:1: expected `=` or `-`");
+  }
+}
@@ -3,11 +3,11 @@ use std::{collections::HashSet, ops::Add};
 use itertools::Itertools;
 use rayon::prelude::*;
 
-use crate::util::{self, Options};
+use crate::{answer::Answer, day::Day, error::Result, input::Input};
 
-crate::tests!(16, (46, 51));
+crate::tests!(Day16, 2023, 16, (46, 51));
 
-enum Tile {
+pub enum Tile {
   Empty,
   Horizontal,
   Vertical,
@@ -15,17 +15,19 @@ enum Tile {
   Backslash,
 }
 
-impl From<char> for Tile {
-  fn from(symbol: char) -> Self {
+impl TryFrom<char> for Tile {
+  type Error = &'static str;
+
+  fn try_from(symbol: char) -> Result<Self, Self::Error> {
     use Tile::*;
 
     match symbol {
-      '.' => Empty,
-      '-' => Horizontal,
-      '|' => Vertical,
-      '/' => Slash,
-      '\\' => Backslash,
-      _ => panic!("unexpected symbol"),
+      '.' => Ok(Empty),
+      '-' => Ok(Horizontal),
+      '|' => Ok(Vertical),
+      '/' => Ok(Slash),
+      '\\' => Ok(Backslash),
+      _ => Err("one of `.`, `-`, `|`, `/` or `\\`"),
     }
   }
 }
@@ -102,10 +104,8 @@ impl Direction {
   }
 }
 
-fn parse_grid(input: &str) -> Grid {
-  let input = util::read_file_lines(input);
-
-  input.iter().map(|line| line.chars().map(Tile::from).collect::<Vec<_>>()).collect::<Vec<_>>()
+fn parse_grid(input: &Input) -> Result<Grid> {
+  input.grid()?.cells(Tile::try_from)
 }
 
 fn walk(grid: &Grid, mut seen: HashSet<Move>, moveset: Move) -> HashSet<Move> {
@@ -164,19 +164,34 @@ fn border_start_moves(grid: &Grid) -> Vec<Move> {
     .collect::<Vec<_>>()
 }
 
-pub fn part1(input: &str, _opts: Options) -> i64 {
-  let grid = parse_grid(input);
-  let moveset = ((0isize, 0isize), Direction::Right);
+pub struct Day16;
 
-  count_energized(walk(&grid, HashSet::default(), moveset))
-}
+impl Day for Day16 {
+  type Input = Grid;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_grid(input)
+  }
 
-pub fn part2(input: &str, _opts: Options) -> i64 {
-  let grid = parse_grid(input);
+  fn fingerprint(input: &Input) -> bool {
+    input.text().contains(['/', '\\'])
+  }
+
+  #[aoc::solution(day = 16, part = 1)]
+  fn part1(grid: &Self::Input, _opts: &Self::Options) -> Answer {
+    let moveset = ((0isize, 0isize), Direction::Right);
 
-  border_start_moves(&grid)
-    .into_par_iter()
-    .map(|moveset| count_energized(walk(&grid, HashSet::default(), moveset)))
-    .max()
-    .unwrap()
+    count_energized(walk(grid, HashSet::default(), moveset)).into()
+  }
+
+  #[aoc::solution(day = 16, part = 2)]
+  fn part2(grid: &Self::Input, _opts: &Self::Options) -> Answer {
+    border_start_moves(grid)
+      .into_par_iter()
+      .map(|moveset| count_energized(walk(grid, HashSet::default(), moveset)))
+      .max()
+      .unwrap()
+      .into()
+  }
 }
@@ -3,9 +3,9 @@ use std::{
   collections::{BinaryHeap, HashMap},
 };
 
-use crate::util::{self, parse, Options};
+use crate::{answer::Answer, day::Day, error::Result, input::Input};
 
-crate::tests!(17, (102, 94));
+crate::tests!(Day17, 2023, 17, (102, 94));
 
 type Grid = Vec<Vec<i64>>;
 type Coord = (isize, isize);
@@ -38,11 +38,8 @@ impl Ord for State {
   }
 }
 
-fn parse_grid(input: &str) -> Grid {
-  util::read_file_lines(input)
-    .iter()
-    .map(|row| row.chars().map(|c| parse::<i64>(&c.to_string())).collect::<Vec<_>>())
-    .collect::<Vec<_>>()
+fn parse_grid(input: &Input) -> Result<Grid> {
+  input.grid()?.cells(|c| c.to_digit(10).map(i64::from).ok_or("a digit"))
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -118,14 +115,27 @@ fn shortest(grid: &Grid, (min, max): MinMax) -> i64 {
   panic!("should not be reached");
 }
 
-pub fn part1(input: &str, _opts: Options) -> i64 {
-  let grid = parse_grid(input);
+pub struct Day17;
 
-  shortest(&grid, (0, 3))
-}
+impl Day for Day17 {
+  type Input = Grid;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_grid(input)
+  }
 
-pub fn part2(input: &str, _opts: Options) -> i64 {
-  let grid = parse_grid(input);
+  fn fingerprint(input: &Input) -> bool {
+    input.bytes().flatten().all(u8::is_ascii_digit)
+  }
 
-  shortest(&grid, (4, 10))
+  #[aoc::solution(day = 17, part = 1)]
+  fn part1(grid: &Self::Input, _opts: &Self::Options) -> Answer {
+    shortest(grid, (0, 3)).into()
+  }
+
+  #[aoc::solution(day = 17, part = 2)]
+  fn part2(grid: &Self::Input, _opts: &Self::Options) -> Answer {
+    shortest(grid, (4, 10)).into()
+  }
 }
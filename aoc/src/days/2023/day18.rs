@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+
+use crate::{answer::Answer, day::Day, error::Result, input::Input, util::Line};
+
+crate::tests!(Day18, 2023, 18, (62, 952408144115));
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+  Up,
+  Left,
+  Right,
+  Down,
+}
+
+impl TryFrom<&str> for Direction {
+  type Error = &'static str;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    use Direction::*;
+
+    match value {
+      "R" | "0" => Ok(Right),
+      "D" | "1" => Ok(Down),
+      "L" | "2" => Ok(Left),
+      "U" | "3" => Ok(Up),
+      _ => Err("a direction"),
+    }
+  }
+}
+
+type Coord = (isize, isize);
+
+#[derive(Debug, Clone, Copy)]
+pub struct Move {
+  direction: Direction,
+  distance: usize,
+}
+
+impl Move {
+  fn parse<'a>(line: &Line<'a>, value: &'a str) -> Result<Self> {
+    let (direction, distance) = line.split_once(value.trim(), " ")?;
+
+    Ok(Move {
+      direction: line.convert(direction)?,
+      distance: line.parse::<usize>(distance)?,
+    })
+  }
+
+  fn parse_hex<'a>(line: &Line<'a>, value: &'a str) -> Result<Self> {
+    let hexstring = value.strip_suffix(')').ok_or_else(|| line.error(value, "`)`"))?;
+
+    if hexstring.len() != 6 || !hexstring.is_ascii() {
+      return Err(line.error(hexstring, "six hexadecimal digits"));
+    }
+
+    let (distance, direction) = hexstring.split_at(5);
+
+    Ok(Move {
+      direction: line.convert(direction)?,
+      distance: usize::from_str_radix(distance, 16).map_err(|_| line.error(distance, "five hexadecimal digits"))?,
+    })
+  }
+}
+
+type Plans = (VecDeque<Move>, VecDeque<Move>);
+
+fn parse_trench(input: &Input) -> Result<Plans> {
+  input
+    .lines()
+    .map(|line| {
+      let (int, hex) = line.split_once(line.text, "(#")?;
+
+      Ok((Move::parse(&line, int)?, Move::parse_hex(&line, hex)?))
+    })
+    .collect()
+}
+
+fn dig(mut trenches: VecDeque<Move>) -> Vec<Coord> {
+  use Direction::*;
+
+  let mut coord = (0, 0);
+  let mut vertices = vec![(0, 0)];
+
+  while let Some(Move { direction, distance }) = trenches.pop_front() {
+    if trenches.is_empty() {
+      vertices.push((0, 0));
+      break;
+    }
+
+    let (x, y) = coord;
+    let distance = distance as isize;
+
+    coord = match direction {
+      Up => (x, y - distance),
+      Left => (x - distance, y),
+      Right => (x + distance, y),
+      Down => (x, y + distance),
+    };
+
+    vertices.push(coord);
+  }
+
+  vertices
+}
+
+// Shoelace formula
+fn lace_shoe((x1, y1): Coord, (x2, y2): Coord) -> isize {
+  (x1 * y2) - (y1 * x2)
+}
+
+fn perimeter(trenches: &VecDeque<Move>) -> isize {
+  trenches.iter().fold(0, |distance, movement| distance + movement.distance as isize)
+}
+
+fn area(vertices: &[Coord], distance: isize) -> i64 {
+  let mut area = 0;
+
+  vertices.windows(2).for_each(|two| {
+    let left = two[0];
+    let right = two[1];
+
+    area += lace_shoe(left, right);
+  });
+
+  // Pick's theorem
+  let area = (area / 2).abs() - distance / 2 + 1;
+
+  (distance + area) as i64
+}
+
+fn lagoon_size(trenches: &VecDeque<Move>) -> i64 {
+  let distance = perimeter(trenches);
+  let vertices = dig(trenches.clone());
+
+  area(&vertices, distance)
+}
+
+pub struct Day18;
+
+impl Day for Day18 {
+  type Input = Plans;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_trench(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.lines().next().is_some_and(|line| line.text.contains("(#"))
+  }
+
+  #[aoc::solution(day = 18, part = 1)]
+  fn part1((trenches, _): &Self::Input, _opts: &Self::Options) -> Answer {
+    lagoon_size(trenches).into()
+  }
+
+  #[aoc::solution(day = 18, part = 2)]
+  fn part2((_, trenches): &Self::Input, _opts: &Self::Options) -> Answer {
+    lagoon_size(trenches).into()
+  }
+}
@@ -0,0 +1,344 @@
+use std::{
+  array,
+  collections::{HashMap, VecDeque},
+  ops::{Index, IndexMut, RangeInclusive},
+};
+
+use crate::{
+  answer::Answer,
+  day::Day,
+  error::{Error, Result},
+  input::Input,
+  util::Line,
+};
+
+crate::tests!(Day19, 2023, 19, (19114, 167409079868000));
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Part {
+  x: i64,
+  m: i64,
+  a: i64,
+  s: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Rating {
+  X,
+  M,
+  A,
+  S,
+}
+
+impl TryFrom<&str> for Rating {
+  type Error = &'static str;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "x" => Ok(Rating::X),
+      "m" => Ok(Rating::M),
+      "a" => Ok(Rating::A),
+      "s" => Ok(Rating::S),
+      _ => Err("one of `x`, `m`, `a` or `s`"),
+    }
+  }
+}
+
+impl Index<Rating> for Part {
+  type Output = i64;
+
+  fn index(&self, rating: Rating) -> &Self::Output {
+    match rating {
+      Rating::X => &self.x,
+      Rating::M => &self.m,
+      Rating::A => &self.a,
+      Rating::S => &self.s,
+    }
+  }
+}
+
+impl IndexMut<Rating> for Part {
+  fn index_mut(&mut self, rating: Rating) -> &mut Self::Output {
+    match rating {
+      Rating::X => &mut self.x,
+      Rating::M => &mut self.m,
+      Rating::A => &mut self.a,
+      Rating::S => &mut self.s,
+    }
+  }
+}
+
+impl Index<Rating> for [RangeInclusive<i64>] {
+  type Output = RangeInclusive<i64>;
+
+  fn index(&self, rating: Rating) -> &Self::Output {
+    match rating {
+      Rating::X => &self[0],
+      Rating::M => &self[1],
+      Rating::A => &self[2],
+      Rating::S => &self[3],
+    }
+  }
+}
+
+impl IndexMut<Rating> for [RangeInclusive<i64>] {
+  fn index_mut(&mut self, rating: Rating) -> &mut Self::Output {
+    match rating {
+      Rating::X => &mut self[0],
+      Rating::M => &mut self[1],
+      Rating::A => &mut self[2],
+      Rating::S => &mut self[3],
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+  rules: Vec<Rule>,
+}
+
+impl Workflow {
+  fn find_destination(&self, part: &Part) -> Destination {
+    use Operation::*;
+
+    for rule in &self.rules {
+      match rule.condition {
+        None => return rule.destination.clone(),
+        Some(condition) => {
+          let rating = &part[condition.rating];
+
+          let result = match condition.op {
+            GreaterThan => rating > &condition.value,
+            Equal => rating == &condition.value,
+            LessThan => rating < &condition.value,
+          };
+
+          if result {
+            return rule.destination.clone();
+          }
+        }
+      }
+    }
+
+    panic!("should never have no rule matching");
+  }
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+  condition: Option<Condition>,
+  destination: Destination,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Condition {
+  rating: Rating,
+  op: Operation,
+  value: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+  GreaterThan,
+  Equal,
+  LessThan,
+}
+
+impl TryFrom<&str> for Operation {
+  type Error = &'static str;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    use Operation::*;
+
+    match value {
+      ">" => Ok(GreaterThan),
+      "=" => Ok(Equal),
+      "<" => Ok(LessThan),
+      _ => Err("one of `<`, `=` or `>`"),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+enum Destination {
+  Workflow(String),
+  Accepted,
+  Rejected,
+}
+
+impl From<&str> for Destination {
+  fn from(value: &str) -> Self {
+    use Destination::*;
+
+    match value {
+      "A" => Accepted,
+      "R" => Rejected,
+      name => Workflow(name.to_string()),
+    }
+  }
+}
+
+type Workflows = HashMap<String, Workflow>;
+
+fn parse_rule<'a>(line: &Line<'a>, rule: &'a str) -> Result<Rule> {
+  let Some((cond, dest)) = rule.split_once(':') else {
+    return Ok(Rule {
+      condition: None,
+      destination: Destination::from(rule),
+    });
+  };
+
+  let index = cond.find(['<', '=', '>']).ok_or_else(|| line.error(cond, "one of `<`, `=` or `>`"))?;
+  let (rating, op, value) = (&cond[..index], &cond[index..index + 1], &cond[index + 1..]);
+
+  Ok(Rule {
+    condition: Some(Condition {
+      rating: line.convert(rating)?,
+      op: line.convert(op)?,
+      value: line.parse::<i64>(value)?,
+    }),
+    destination: Destination::from(dest),
+  })
+}
+
+fn parse_input(input: &Input) -> Result<(Workflows, Vec<Part>)> {
+  let mut blocks = input.blocks();
+  let mut workflows = Workflows::new();
+  let mut references: Vec<(Line, &str)> = vec![];
+
+  for line in blocks.next().into_iter().flat_map(|block| block.lines()) {
+    let (name, rules) = line.split_once(line.text, "{")?;
+    let rules = rules.strip_suffix('}').ok_or_else(|| line.error(rules, "`}`"))?;
+
+    let mut parsed = vec![];
+
+    for rule in rules.split(',') {
+      let parsed_rule = parse_rule(&line, rule)?;
+
+      if let Destination::Workflow(_) = parsed_rule.destination {
+        references.push((line, rule.rsplit_once(':').map_or(rule, |(_, dest)| dest)));
+      }
+
+      parsed.push(parsed_rule);
+    }
+
+    if parsed.last().is_some_and(|rule| rule.condition.is_some()) {
+      return Err(line.error(rules, "a fallback rule"));
+    }
+
+    workflows.insert(name.to_string(), Workflow { rules: parsed });
+  }
+
+  if !workflows.contains_key("in") {
+    return Err(Error::end_of_input(input.text(), "an `in` workflow"));
+  }
+
+  if let Some((line, reference)) = references.into_iter().find(|(_, reference)| !workflows.contains_key(*reference)) {
+    return Err(line.error(reference, "a known workflow"));
+  }
+
+  let parts = blocks
+    .flat_map(|block| block.lines())
+    .map(|line| {
+      let specs = line.strip_prefix(line.text, "{")?;
+      let specs = specs.strip_suffix('}').ok_or_else(|| line.error(specs, "`}`"))?;
+
+      specs.split(',').try_fold(Part::default(), |mut acc, spec| {
+        let (rating, value) = line.split_once(spec, "=")?;
+
+        acc[line.convert::<Rating>(rating)?] = line.parse::<i64>(value)?;
+
+        Ok(acc)
+      })
+    })
+    .collect::<Result<Vec<_>>>()?;
+
+  Ok((workflows, parts))
+}
+
+fn find_accepted_ranges(workflows: &Workflows, dest: Destination, mut ranges: [RangeInclusive<i64>; 4]) -> u128 {
+  use Operation::*;
+
+  let mut total = 0;
+
+  match dest {
+    Destination::Rejected => return 0,
+    Destination::Accepted => return ranges.iter().map(|range| (range.end() - range.start() + 1) as u128).product(),
+
+    Destination::Workflow(workflow) => {
+      for rule in &workflows.get(&workflow).unwrap().rules {
+        match rule.condition {
+          None => total += find_accepted_ranges(workflows, rule.destination.clone(), ranges.clone()),
+
+          Some(condition) => {
+            let mut selection = ranges.clone();
+            let (lrange, rrange) = (ranges[condition.rating].start(), ranges[condition.rating].end());
+
+            if condition.op == LessThan && *lrange < condition.value {
+              selection[condition.rating] = *lrange..=condition.value - 1;
+
+              total += find_accepted_ranges(workflows, rule.destination.clone(), selection);
+
+              if condition.value < *rrange {
+                ranges[condition.rating] = condition.value..=*rrange;
+              }
+            } else if *rrange > condition.value {
+              selection[condition.rating] = condition.value + 1..=*rrange;
+
+              total += find_accepted_ranges(workflows, rule.destination.clone(), selection);
+
+              if *lrange <= condition.value {
+                ranges[condition.rating] = *lrange..=condition.value;
+              }
+            }
+          }
+        }
+      }
+    }
+  }
+
+  total
+}
+
+pub struct Day19;
+
+impl Day for Day19 {
+  type Input = (Workflows, Vec<Part>);
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_input(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.text().contains("\n{x=")
+  }
+
+  #[aoc::solution(day = 19, part = 1)]
+  fn part1((workflows, parts): &Self::Input, _opts: &Self::Options) -> Answer {
+    let mut queue: VecDeque<(String, Part)> = VecDeque::default();
+    let mut accepted: Vec<Part> = vec![];
+
+    for part in parts {
+      queue.push_back(("in".to_string(), *part));
+    }
+
+    while let Some((name, part)) = queue.pop_front() {
+      let workflow = workflows.get(&name).unwrap();
+      let destination = workflow.find_destination(&part);
+
+      match destination {
+        Destination::Accepted => accepted.push(part),
+        Destination::Workflow(rule) => queue.push_back((rule, part)),
+        _ => {}
+      }
+    }
+
+    accepted.into_iter().map(|part| part.x + part.m + part.a + part.s).sum::<i64>().into()
+  }
+
+  #[aoc::solution(day = 19, part = 2)]
+  fn part2((workflows, _): &Self::Input, _opts: &Self::Options) -> Answer {
+    find_accepted_ranges(workflows, Destination::Workflow("in".to_string()), array::from_fn(|_| 1..=4000)).into()
+  }
+}
@@ -0,0 +1,229 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc::FromInput;
+use num::integer::lcm;
+
+use crate::{
+  answer::Answer,
+  day::Day,
+  error::Result,
+  input::Input,
+  util::{trace, FromInput},
+};
+
+crate::tests!(Day20, 2023, 20, (11687500, 0));
+
+#[derive(Debug, Clone)]
+pub enum Device {
+  Broadcaster { name: String, outputs: Vec<String> },
+  FlipFlop { name: String, state: bool, outputs: Vec<String> },
+  Conjunction { name: String, inputs: HashMap<String, Pulse>, outputs: Vec<String> },
+}
+
+impl Device {
+  fn outputs(&self) -> &[String] {
+    use Device::*;
+
+    match self {
+      Broadcaster { outputs, .. } => outputs,
+      FlipFlop { outputs, .. } => outputs,
+      Conjunction { outputs, .. } => outputs,
+    }
+  }
+
+  fn send(&mut self, pulse: Pulse, from: &str, queue: &mut VecDeque<(String, String, Pulse)>) {
+    use Device::*;
+
+    match self {
+      Broadcaster { name, outputs } => {
+        for output in outputs {
+          queue.push_back((name.to_string(), output.to_string(), pulse));
+        }
+      }
+
+      FlipFlop { name, state, outputs } => match pulse {
+        Pulse::High => {}
+        Pulse::Low => {
+          let pulse = match state {
+            false => Pulse::High,
+            true => Pulse::Low,
+          };
+
+          for output in outputs {
+            queue.push_back((name.to_string(), output.to_string(), pulse));
+          }
+
+          *state = !*state;
+        }
+      },
+
+      Conjunction { name, inputs, outputs } => {
+        inputs.insert(from.to_string(), pulse);
+
+        let pulse = match inputs.iter().all(|(_, pulse)| pulse == &Pulse::High) {
+          true => Pulse::Low,
+          false => Pulse::High,
+        };
+
+        for output in outputs {
+          queue.push_back((name.clone(), output.clone(), pulse));
+        }
+      }
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pulse {
+  High,
+  Low,
+}
+
+type Network = HashMap<String, Device>;
+
+#[derive(FromInput)]
+enum Label {
+  #[aoc(fmt = "broadcaster")]
+  Broadcaster,
+  #[aoc(fmt = "%{0}")]
+  FlipFlop(String),
+  #[aoc(fmt = "&{0}")]
+  Conjunction(String),
+}
+
+#[derive(FromInput)]
+#[aoc(fmt = "{label} -> {outputs}")]
+struct Module {
+  label: Label,
+  #[aoc(sep = ", ")]
+  outputs: Vec<String>,
+}
+
+fn parse_network(input: &Input) -> Result<Network> {
+  use Device::*;
+
+  let mut network = Module::from_lines(input.lines())?
+    .into_iter()
+    .map(|Module { label, outputs }| match label {
+      Label::Broadcaster => (
+        "broadcaster".to_string(),
+        Broadcaster {
+          name: "broadcaster".to_string(),
+          outputs,
+        },
+      ),
+      Label::FlipFlop(name) => (name.clone(), FlipFlop { name, state: false, outputs }),
+      Label::Conjunction(name) => (
+        name.clone(),
+        Conjunction {
+          name,
+          inputs: HashMap::default(),
+          outputs,
+        },
+      ),
+    })
+    .collect::<Network>();
+
+  for (name, device) in network.clone() {
+    for output in device.outputs() {
+      if let Some(Conjunction { inputs, .. }) = network.get_mut(output) {
+        inputs.insert(name.clone(), Pulse::Low);
+      }
+    }
+  }
+
+  Ok(network)
+}
+
+pub struct Day20;
+
+impl Day for Day20 {
+  type Input = Network;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    parse_network(input)
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.lines().any(|line| line.text.starts_with("broadcaster ->"))
+  }
+
+  #[aoc::solution(day = 20, part = 1)]
+  fn part1(network: &Self::Input, _opts: &Self::Options) -> Answer {
+    let mut network = network.clone();
+    let (mut highs, mut lows): (i64, i64) = (0, 0);
+
+    for _ in 1..=1000 {
+      let mut queue: VecDeque<(String, String, Pulse)> = VecDeque::default();
+      queue.push_back(("button".to_string(), "broadcaster".to_string(), Pulse::Low));
+
+      while let Some((from, target, pulse)) = queue.pop_front() {
+        match pulse {
+          Pulse::High => highs += 1,
+          Pulse::Low => lows += 1,
+        }
+
+        if let Some(device) = network.get_mut(&target) {
+          device.send(pulse, &from, &mut queue);
+        }
+      }
+    }
+
+    trace::counter("day 20 pulses", (highs + lows) as u64);
+
+    (highs * lows).into()
+  }
+
+  #[aoc::solution(day = 20, part = 2)]
+  fn part2(network: &Self::Input, _opts: &Self::Options) -> Answer {
+    use Device::*;
+
+    let mut network = network.clone();
+    let mut tracker: HashMap<String, i64> = HashMap::default();
+
+    let mut presses = 1;
+
+    let previous = network.iter().find(|(_, device)| device.outputs().contains(&String::from("rx"))).map(|(device, _)| device.clone());
+
+    if previous.is_none() {
+      eprintln!("WARN: this exercise does not have an example for part 2.");
+      return 0.into();
+    }
+
+    let previous = previous.unwrap();
+    let mut progress = trace::progress("day 20 button presses", None);
+
+    loop {
+      progress.tick(presses as u64);
+
+      let mut queue: VecDeque<(String, String, Pulse)> = VecDeque::default();
+      queue.push_back(("button".to_string(), "broadcaster".to_string(), Pulse::Low));
+
+      while let Some((from, target, pulse)) = queue.pop_front() {
+        if let Some(device) = network.get_mut(&target) {
+          device.send(pulse, &from, &mut queue);
+
+          // mg is the only node outputing to rx, and it is a conjunction, so let
+          // us track the iteration at which its states change and LCM them.
+          if let Conjunction { name, inputs, .. } = &device {
+            if name == &previous {
+              for (input, last_pulse) in inputs {
+                if !tracker.contains_key(input) && last_pulse == &Pulse::High {
+                  trace::counter(format_args!("day 20 first high pulse from {input}"), presses as u64);
+                  tracker.insert(input.to_string(), presses);
+                }
+              }
+
+              if tracker.len() == inputs.len() {
+                return tracker.values().fold(1, |acc, press| lcm(acc, *press)).into();
+              }
+            }
+          }
+        }
+      }
+
+      presses += 1;
+    }
+  }
+}
@@ -0,0 +1,108 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+  answer::Answer,
+  day::Day,
+  error::{Error, Result},
+  input::Input,
+  util::trace,
+};
+
+crate::tests!(Day21, 2023, 21, {
+  part1: [({ steps: 6 } example() => 16), ({ steps: 1 } inline("...\n.S#\n...") => 3)],
+  part2: [],
+});
+
+crate::options! {
+  pub struct Day21Options {
+    steps: usize = 64 => "number of steps the elf takes from the starting position",
+  }
+}
+
+type Grid = Vec<Vec<char>>;
+
+const ADJACENCY_MATRIX: [(isize, isize); 4] = [(0, -1), (-1, 0), (0, 1), (1, 0)];
+
+fn parse_grid(input: &Input) -> Result<Grid> {
+  input.grid()?.cells(|symbol| match symbol {
+    '.' | '#' | 'S' => Ok(symbol),
+    _ => Err("one of `.`, `#` or `S`"),
+  })
+}
+
+fn find_start(grid: &Grid) -> (isize, isize) {
+  grid
+    .iter()
+    .enumerate()
+    .find_map(|(y, row)| Some((y as isize, row.iter().position(|symbol| *symbol == 'S')? as isize)))
+    .expect("parsed gardens have a starting position")
+}
+
+fn map_gardens(grid: &Grid, max: usize, at: (isize, isize)) -> i64 {
+  let mut gardens: HashSet<(isize, isize)> = HashSet::default();
+  let mut seen: HashSet<((isize, isize), usize)> = HashSet::default();
+
+  let mut queue: VecDeque<((isize, isize), usize)> = VecDeque::default();
+  queue.push_back((at, 0));
+
+  let mut progress = trace::progress("day 21 states explored", None);
+
+  while let Some(iter @ (garden @ (y, x), steps)) = queue.pop_front() {
+    if seen.contains(&iter) {
+      continue;
+    }
+
+    seen.insert(iter);
+    progress.tick(seen.len() as u64);
+
+    if steps == max {
+      gardens.insert(garden);
+    } else {
+      for (y_add, x_add) in ADJACENCY_MATRIX {
+        let (y_next, x_next) = (y + y_add, x + x_add);
+
+        if let Some(symbol) = grid
+          .get((y_next.rem_euclid(grid.len() as isize)) as usize)
+          .and_then(|row| row.get((x_next.rem_euclid(grid[0].len() as isize)) as usize))
+        {
+          if symbol != &'#' {
+            queue.push_back(((y_next, x_next), steps + 1));
+          }
+        }
+      }
+    }
+  }
+
+  trace::counter("day 21 states explored", seen.len() as u64);
+
+  gardens.len() as i64
+}
+
+pub struct Day21;
+
+impl Day for Day21 {
+  type Input = Grid;
+  type Options = Day21Options;
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    let grid = parse_grid(input)?;
+
+    match grid.iter().flatten().any(|symbol| *symbol == 'S') {
+      true => Ok(grid),
+      false => Err(Error::end_of_input(input.text(), "a starting position `S`")),
+    }
+  }
+
+  fn fingerprint(input: &Input) -> bool {
+    input.text().matches('S').count() == 1
+  }
+
+  #[aoc::solution(day = 21, part = 1)]
+  fn part1(grid: &Self::Input, opts: &Self::Options) -> Answer {
+    map_gardens(grid, opts.steps, find_start(grid)).into()
+  }
+
+  fn part2(_grid: &Self::Input, _opts: &Self::Options) -> Answer {
+    0.into()
+  }
+}
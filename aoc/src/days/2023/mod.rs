@@ -0,0 +1 @@
+automod::dir!(pub "src/days/2023");
@@ -0,0 +1,3 @@
+// One module per event, the days of which are picked up by `generate_days!`.
+#[path = "2023/mod.rs"]
+pub mod y2023;
@@ -0,0 +1,148 @@
+use std::{fmt, io};
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum Error {
+  Io { file: String, source: io::Error },
+  Parse(ParseError),
+}
+
+// Positions are 1-based, like the ones reported by rustc, and `span` is the
+// length of the offending token, used to underline it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+  pub file: Option<String>,
+  pub line: usize,
+  pub column: usize,
+  pub span: usize,
+  pub expected: String,
+}
+
+impl Error {
+  pub fn parse<E>(line: usize, column: usize, span: usize, expected: E) -> Self
+  where
+    E: fmt::Display,
+  {
+    Error::Parse(ParseError {
+      file: None,
+      line,
+      column,
+      span: span.max(1),
+      expected: expected.to_string(),
+    })
+  }
+
+  // Locates a byte range of the content, as reported by parsers working on a
+  // whole file rather than line by line.
+  pub fn at_offset<E>(content: &str, span: std::ops::Range<usize>, expected: E) -> Self
+  where
+    E: fmt::Display,
+  {
+    let before = &content[..span.start.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or_default().chars().count() + 1;
+    let length = content.get(span).unwrap_or_default().chars().take_while(|c| *c != '\n').count();
+
+    Error::parse(line, column, length, expected)
+  }
+
+  // Points right after the last character of the input, for inputs that are
+  // missing whole lines or sections.
+  pub fn end_of_input<E>(text: &str, expected: E) -> Self
+  where
+    E: fmt::Display,
+  {
+    match text.lines().enumerate().last() {
+      Some((index, last)) => Error::parse(index + 1, last.chars().count() + 1, 1, expected),
+      None => Error::parse(1, 1, 1, expected),
+    }
+  }
+
+  pub fn in_file(self, file: &str) -> Self {
+    match self {
+      Error::Parse(error) => Error::Parse(ParseError {
+        file: Some(file.to_string()),
+        ..error
+      }),
+      error => error,
+    }
+  }
+
+  // Renders the error in the style of rustc's diagnostics, quoting the
+  // offending line from the text of the input when it is available.
+  pub fn render(&self, text: &str) -> String {
+    let error = match self {
+      Error::Io { .. } => return format!("error: {self}"),
+      Error::Parse(error) => error,
+    };
+
+    let number = error.line.to_string();
+    let gutter = " ".repeat(number.len());
+    let mut output = format!("error: expected {}\n", error.expected);
+
+    output.push_str(&format!("{gutter}--> {}:{}:{}\n", error.file.as_deref().unwrap_or("<input>"), error.line, error.column));
+    output.push_str(&format!("{gutter} |\n"));
+
+    // Empty inputs have no line to quote, the error still points at where the
+    // first one would be.
+    let line = text.lines().nth(error.line.saturating_sub(1)).unwrap_or_default();
+
+    output.push_str(&format!("{number} | {line}\n"));
+    output.push_str(&format!("{gutter} | {}{}", " ".repeat(error.column.saturating_sub(1)), "^".repeat(error.span)));
+
+    output
+  }
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Error::Io { file, source } => write!(f, "could not read {file}: {source}"),
+      Error::Parse(error) => write!(f, "{}:{}:{}: expected {}", error.file.as_deref().unwrap_or("<input>"), error.line, error.column, error.expected),
+    }
+  }
+}
+
+impl std::error::Error for Error {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      Error::Io { source, .. } => Some(source),
+      Error::Parse(_) => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Error;
+  use crate::input::Input;
+
+  #[test]
+  fn render_points_at_token() {
+    let input = Input::new("Game 1: 3 blue\nGame 2: 4 bleu");
+    let line = input.lines().nth(1).unwrap();
+    let error = line.error(&line.text[10..], "a color").in_file("data/02.txt");
+
+    assert_eq!(error.to_string(), "data/02.txt:2:11: expected a color");
+    assert_eq!(
+      error.render(input.text()),
+      "error: expected a color\n --> data/02.txt:2:11\n  |\n2 | Game 2: 4 bleu\n  |           ^^^^"
+    );
+  }
+
+  #[test]
+  fn empty_input() {
+    let input = Input::new("");
+    let error = Error::end_of_input(input.text(), "a `Time:` line").in_file("data/06.txt");
+
+    assert_eq!(error.to_string(), "data/06.txt:1:1: expected a `Time:` line");
+    assert_eq!(error.render(input.text()), "error: expected a `Time:` line\n --> data/06.txt:1:1\n  |\n1 | \n  | ^");
+    assert_eq!(Error::parse(0, 0, 1, "a digit").render(input.text()), "error: expected a digit\n --> <input>:0:0\n  |\n0 | \n  | ^");
+  }
+
+  #[test]
+  fn end_of_input() {
+    assert_eq!(Error::end_of_input("Time: 7 15\n", "a `Distance:` line").to_string(), "<input>:1:11: expected a `Distance:` line");
+  }
+}
@@ -0,0 +1,261 @@
+use std::{
+  collections::BTreeMap,
+  fs::{self, OpenOptions},
+  io::{self, Write},
+  path::{Path, PathBuf},
+  process::{self, Command},
+  time::Duration,
+};
+
+use chrono::{SecondsFormat, Utc};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  cli::{fail, fail_in, Cli},
+  error::{Error, Result},
+  output::Record,
+  util,
+};
+
+// Every run appends one JSON line to the history, holding the time it took to
+// solve each part (the median when benchmarking).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Run {
+  pub revision: String,
+  pub timestamp: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub baseline: Option<String>,
+  pub test: bool,
+  pub timings: Vec<Timing>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+  pub year: usize,
+  pub day: usize,
+  pub part: usize,
+  pub ns: u64,
+}
+
+pub fn history_file() -> PathBuf {
+  util::data_dir().join("history.jsonl")
+}
+
+// The revision is suffixed with `-dirty` when the working tree has changes,
+// since the timings then do not belong to the committed code.
+fn revision() -> String {
+  let git = |args: &[&str]| {
+    Command::new("git")
+      .args(args)
+      .output()
+      .ok()
+      .filter(|output| output.status.success())
+      .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+  };
+
+  match (git(&["rev-parse", "--short", "HEAD"]), git(&["status", "--porcelain", "--untracked-files=no"])) {
+    (Some(revision), Some(status)) if !status.is_empty() => format!("{revision}-dirty"),
+    (Some(revision), _) => revision,
+    (None, _) => "unknown".to_string(),
+  }
+}
+
+impl Run {
+  pub fn new(records: &[Record], test: bool, baseline: Option<String>) -> Run {
+    let timings = records
+      .iter()
+      .filter_map(|record| {
+        Some(Timing {
+          year: record.year,
+          day: record.day,
+          part: record.part,
+          ns: record.bench.map(|stats| stats.median).or(record.solve)?.as_nanos() as u64,
+        })
+      })
+      .collect();
+
+    Run {
+      revision: revision(),
+      timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+      baseline,
+      test,
+      timings,
+    }
+  }
+
+  pub fn append(&self, file: &Path) -> io::Result<()> {
+    let mut history = OpenOptions::new().create(true).append(true).open(file)?;
+
+    writeln!(history, "{}", serde_json::to_string(self)?)
+  }
+}
+
+pub fn load(file: &Path) -> Result<Vec<Run>> {
+  let content = match fs::read_to_string(file) {
+    Ok(content) => content,
+    Err(source) if source.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+    Err(source) => {
+      return Err(Error::Io {
+        file: util::source_name(file),
+        source,
+      })
+    }
+  };
+
+  content
+    .lines()
+    .enumerate()
+    .filter(|(_, line)| !line.trim().is_empty())
+    .map(|(index, line)| serde_json::from_str::<Run>(line).map_err(|error| Error::parse(index + 1, error.column(), 1, format!("a history entry ({error})"))))
+    .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Delta {
+  pub year: usize,
+  pub day: usize,
+  pub part: usize,
+  pub before: Option<Duration>,
+  pub after: Duration,
+}
+
+impl Delta {
+  pub fn ratio(&self) -> Option<f64> {
+    self.before.filter(|before| !before.is_zero()).map(|before| self.after.as_secs_f64() / before.as_secs_f64() - 1.0)
+  }
+}
+
+// Compares the latest timing of each part against the one before it, or
+// against the latest run tagged with the given baseline. Only runs on the same
+// kind of inputs (examples or real inputs) are compared.
+pub fn compare(runs: &[Run], test: bool, baseline: Option<&str>) -> Vec<Delta> {
+  let mut latest: BTreeMap<(usize, usize, usize), Vec<Duration>> = BTreeMap::new();
+  let mut reference: BTreeMap<(usize, usize, usize), Duration> = BTreeMap::new();
+
+  for run in runs.iter().filter(|run| run.test == test) {
+    for timing in &run.timings {
+      let duration = Duration::from_nanos(timing.ns);
+
+      latest.entry((timing.year, timing.day, timing.part)).or_default().push(duration);
+
+      if baseline.is_some() && run.baseline.as_deref() == baseline {
+        reference.insert((timing.year, timing.day, timing.part), duration);
+      }
+    }
+  }
+
+  latest
+    .into_iter()
+    .filter_map(|((year, day, part), timings)| {
+      let (&after, previous) = timings.split_last()?;
+
+      let before = match baseline {
+        Some(_) => reference.get(&(year, day, part)).copied(),
+        None => previous.last().copied(),
+      };
+
+      Some(Delta { year, day, part, before, after })
+    })
+    .collect()
+}
+
+pub fn table(deltas: &[Delta], threshold: f64) -> String {
+  let header = ["Year", "Day", "Part", "Before", "After", "Delta", ""].map(String::from);
+
+  let rows = deltas
+    .iter()
+    .map(|delta| {
+      let (change, flag) = match delta.ratio() {
+        Some(ratio) => (format!("{:+.1}%", ratio * 100.0), if ratio * 100.0 > threshold { "regression" } else { "" }),
+        None => ("new".to_string(), ""),
+      };
+
+      [
+        delta.year.to_string(),
+        format!("{:0>2}", delta.day),
+        format!("{:0>2}", delta.part),
+        delta.before.map(|before| format!("{before:.2?}")).unwrap_or_else(|| "-".to_string()),
+        format!("{:.2?}", delta.after),
+        change,
+        flag.to_string(),
+      ]
+    })
+    .collect::<Vec<_>>();
+
+  let widths = (0..header.len())
+    .map(|column| rows.iter().map(|row| row[column].chars().count()).chain([header[column].len()]).max().unwrap_or(0))
+    .collect::<Vec<_>>();
+
+  let render = |cells: &[String]| cells.iter().zip(&widths).map(|(cell, width)| format!("{cell:>width$}")).join("  ").trim_end().to_string();
+
+  [render(&header), widths.iter().map(|width| "-".repeat(*width)).join("  ").trim_end().to_string()]
+    .into_iter()
+    .chain(rows.iter().map(|row| render(row)))
+    .join("\n")
+}
+
+// Compares the latest timings against the previous run or a baseline, failing
+// when a part got slower than the threshold.
+pub fn run(cli: &Cli) -> ! {
+  let file = history_file();
+  let runs = load(&file).unwrap_or_else(|error| fail_in(error, &file, &util::read_data(&file).unwrap_or_default()));
+  let deltas = compare(&runs, cli.test, cli.baseline.as_deref());
+
+  if deltas.is_empty() {
+    fail("No timings were recorded yet.");
+  }
+
+  println!("{}", table(&deltas, cli.threshold));
+
+  match deltas.iter().any(|delta| delta.ratio().is_some_and(|ratio| ratio * 100.0 > cli.threshold)) {
+    true => process::exit(1),
+    false => process::exit(0),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use super::{Run, Timing};
+
+  fn run(ns: &[u64], baseline: Option<&str>) -> Run {
+    Run {
+      revision: "abcdef0".to_string(),
+      timestamp: "2023-12-25T00:00:00Z".to_string(),
+      baseline: baseline.map(String::from),
+      test: false,
+      timings: ns
+        .iter()
+        .enumerate()
+        .map(|(index, ns)| Timing {
+          year: 2023,
+          day: index + 1,
+          part: 1,
+          ns: *ns,
+        })
+        .collect(),
+    }
+  }
+
+  #[test]
+  fn compare_previous() {
+    let deltas = super::compare(&[run(&[100, 100], None), run(&[150], None)], false, None);
+
+    assert_eq!(deltas.len(), 2);
+    assert_eq!(deltas[0].before, Some(Duration::from_nanos(100)));
+    assert_eq!(deltas[0].ratio(), Some(0.5));
+    assert_eq!(deltas[1].before, None);
+    assert_eq!(deltas[1].after, Duration::from_nanos(100));
+  }
+
+  #[test]
+  fn compare_baseline() {
+    let deltas = super::compare(&[run(&[200], Some("before")), run(&[100], None), run(&[50], None)], false, Some("before"));
+
+    assert_eq!(deltas[0].before, Some(Duration::from_nanos(200)));
+    assert_eq!(deltas[0].ratio(), Some(-0.75));
+    assert!(super::compare(&[run(&[100], None)], true, None).is_empty());
+  }
+}
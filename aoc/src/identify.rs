@@ -0,0 +1,147 @@
+use std::process;
+
+use crate::{
+  cli::{fail, Cli},
+  day::Solution,
+  input::Input,
+  isolate,
+  output::Outcome,
+  runner::{self, Settings},
+  util,
+};
+
+pub struct Candidate<'a> {
+  pub solution: &'a Solution,
+  pub parses: bool,
+  pub fingerprint: bool,
+  // Share of the evidence pointing at this day, out of every candidate.
+  pub confidence: f64,
+}
+
+impl Candidate<'_> {
+  // Parsing an input at all is a hint, many days read lists of numbers or
+  // grids. Having the fingerprint of the day on top of it is a strong one. A
+  // fingerprint on an input the day cannot parse is still a hint, of a broken
+  // input of that day.
+  fn weight(&self) -> f64 {
+    match (self.parses, self.fingerprint) {
+      (true, true) => 10.0,
+      (true, false) => 1.0,
+      (false, true) => 0.5,
+      (false, false) => 0.0,
+    }
+  }
+
+  pub fn evidence(&self) -> &'static str {
+    match (self.parses, self.fingerprint) {
+      (true, true) => "parses, fingerprint",
+      (true, false) => "parses",
+      _ => "fingerprint, does not parse",
+    }
+  }
+}
+
+// Every day the input could be for, the most likely first.
+pub fn candidates<'a>(solutions: impl Iterator<Item = &'a Solution>, input: &Input) -> Vec<Candidate<'a>> {
+  let mut candidates = solutions
+    .map(|solution| Candidate {
+      solution,
+      parses: matches!(isolate::catch(|| solution.parse(input)), Ok(Ok(_))),
+      fingerprint: solution.fingerprint(input),
+      confidence: 0.0,
+    })
+    .filter(|candidate| candidate.weight() > 0.0)
+    .collect::<Vec<_>>();
+
+  let total = candidates.iter().map(Candidate::weight).sum::<f64>();
+
+  for candidate in &mut candidates {
+    candidate.confidence = candidate.weight() / total;
+  }
+
+  candidates.sort_by(|a, b| b.weight().total_cmp(&a.weight()).then((a.solution.year, a.solution.day).cmp(&(b.solution.year, b.solution.day))));
+  candidates
+}
+
+// The day to run, when one is more likely than all the others.
+pub fn best<'a>(candidates: &[Candidate<'a>]) -> Option<&'a Solution> {
+  match candidates {
+    [first, second, ..] if first.weight() == second.weight() => None,
+    [first, ..] if first.parses => Some(first.solution),
+    _ => None,
+  }
+}
+
+// Tells which day an input is most likely for, from the days that can parse it
+// and the shape of the input, and optionally runs it.
+pub fn run(cli: &Cli, solutions: &[Solution]) -> ! {
+  let file = cli.input.clone().unwrap_or_default();
+
+  // The input is read once, and could not be read again to be run.
+  if cli.run && file == util::STDIN {
+    fail("Inputs read from stdin cannot be run, give a file.");
+  }
+
+  let input = Input::read(&file).unwrap_or_else(|error| fail(error));
+
+  if input.text().trim().is_empty() {
+    fail(format!("{file} is empty, there is nothing to identify."));
+  }
+
+  let candidates = candidates(solutions.iter().filter(|solution| cli.year.is_none_or(|year| solution.year == year)), &input);
+
+  if candidates.is_empty() {
+    fail(format!("No day can read {file}."));
+  }
+
+  for candidate in &candidates {
+    let (year, day) = (candidate.solution.year, candidate.solution.day);
+
+    println!("{year} day {day:<2}  {:>3.0}%  {}", candidate.confidence * 100.0, candidate.evidence());
+  }
+
+  if !cli.run {
+    process::exit(0);
+  }
+
+  let Some(solution) = best(&candidates) else {
+    fail("\nNo day stands out, run one of them with --input.");
+  };
+
+  let options = runner::configure(solution, false, &[]).unwrap_or_else(|message| fail(message));
+
+  println!();
+
+  let records = runner::execute(solution, &solution.parts(), &options, &Settings::new(cli));
+
+  for record in &records {
+    println!("{}", record.text(true));
+  }
+
+  match records.iter().all(|record| record.outcome == Outcome::Solved) {
+    true => process::exit(0),
+    false => process::exit(1),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use aoc_macros::generate_days;
+
+  use crate::{input::Input, util};
+
+  #[test]
+  fn candidates() {
+    let solutions = generate_days!();
+
+    for solution in &solutions {
+      for part in solution.parts() {
+        let input = Input::read(util::input_file(solution.year, solution.day, part, true)).unwrap();
+        let candidates = super::candidates(solutions.iter(), &input);
+
+        assert_eq!(super::best(&candidates).map(|best| best.day), Some(solution.day));
+        assert!(candidates[0].confidence > 0.5);
+      }
+    }
+  }
+}
@@ -0,0 +1,233 @@
+use std::{io, iter, path::Path};
+
+use crate::{
+  error::{Error, Result},
+  util::{self, Line},
+};
+
+// A puzzle input, read once and lent out as lines, blocks or grids that all
+// borrow from it while it is parsed. The parsed input does not borrow from it,
+// as it outlives it to be solved on other threads or kept between runs, so
+// what days keep of it is copied into owned values. Line endings are
+// normalised to `\n`, and trailing newlines are dropped, so that inputs saved
+// on any platform read the same.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+  text: String,
+}
+
+impl Input {
+  pub fn new<S>(text: S) -> Input
+  where
+    S: Into<String>,
+  {
+    let mut text = text.into();
+
+    if text.contains('\r') {
+      text = text.replace("\r\n", "\n");
+    }
+
+    text.truncate(text.trim_end_matches('\n').len());
+
+    Input { text }
+  }
+
+  pub fn read<P>(input: P) -> Result<Input>
+  where
+    P: AsRef<Path>,
+  {
+    let input = input.as_ref();
+
+    let content = match input == Path::new(util::STDIN) {
+      true => io::read_to_string(io::stdin()),
+      false => util::read_data(input),
+    };
+
+    match content {
+      Ok(content) => Ok(Input::new(content)),
+      Err(source) => Err(Error::Io {
+        file: util::source_name(input),
+        source,
+      }),
+    }
+  }
+
+  pub fn text(&self) -> &str {
+    &self.text
+  }
+
+  fn block(&self) -> Block<'_> {
+    Block { text: &self.text, first: 1 }
+  }
+
+  pub fn lines(&self) -> impl Iterator<Item = Line<'_>> + Clone {
+    self.block().lines()
+  }
+
+  pub fn bytes(&self) -> impl Iterator<Item = &[u8]> {
+    self.text.lines().map(str::as_bytes)
+  }
+
+  // Runs of lines separated by blank lines, which are never part of a block.
+  pub fn blocks(&self) -> impl Iterator<Item = Block<'_>> {
+    let text = &self.text;
+    let mut lines = self.lines().peekable();
+
+    iter::from_fn(move || {
+      while lines.next_if(|line| line.text.trim().is_empty()).is_some() {}
+
+      let first = lines.next()?;
+      let mut last = first;
+
+      while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty()) {
+        last = line;
+      }
+
+      let (start, end) = (offset(text, first.text), offset(text, last.text) + last.text.len());
+
+      Some(Block {
+        text: &text[start..end],
+        first: first.number,
+      })
+    })
+  }
+
+  pub fn grid(&self) -> Result<Grid<'_>> {
+    self.block().grid()
+  }
+}
+
+// Where a slice of the text starts, in bytes.
+fn offset(text: &str, slice: &str) -> usize {
+  slice.as_ptr() as usize - text.as_ptr() as usize
+}
+
+// Consecutive lines of an input, numbered as they are in the whole input so
+// that errors point at the right place.
+#[derive(Debug, Clone, Copy)]
+pub struct Block<'a> {
+  pub text: &'a str,
+  first: usize,
+}
+
+impl<'a> Block<'a> {
+  pub fn lines(&self) -> impl Iterator<Item = Line<'a>> + Clone {
+    let first = self.first;
+
+    self.text.lines().enumerate().map(move |(index, text)| Line { number: first + index, text })
+  }
+
+  // Every line of the block must be as wide as the first one.
+  pub fn grid(&self) -> Result<Grid<'a>> {
+    let Some(first) = self.text.lines().next() else {
+      return Err(Error::parse(self.first, 1, 1, "a grid"));
+    };
+
+    let width = first.len();
+
+    for line in self.lines() {
+      if line.text.len() != width || !line.text.is_ascii() {
+        return Err(line.error(line.text, format!("a row of {width} tiles")));
+      }
+    }
+
+    Ok(Grid {
+      bytes: self.text.as_bytes(),
+      width,
+      first: self.first,
+    })
+  }
+}
+
+// A rectangular grid of ASCII tiles, right over the text of the input, rows
+// being separated by a newline.
+#[derive(Debug, Clone, Copy)]
+pub struct Grid<'a> {
+  bytes: &'a [u8],
+  width: usize,
+  first: usize,
+}
+
+impl<'a> Grid<'a> {
+  pub fn width(&self) -> usize {
+    self.width
+  }
+
+  pub fn height(&self) -> usize {
+    (self.bytes.len() + 1) / (self.width + 1)
+  }
+
+  pub fn rows(&self) -> impl Iterator<Item = &'a [u8]> + Clone {
+    self.bytes.split(|byte| *byte == b'\n')
+  }
+
+  pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+    match x < self.width && y < self.height() {
+      true => Some(self.bytes[y * (self.width + 1) + x]),
+      false => None,
+    }
+  }
+
+  // Coordinates of the first tile with the given symbol, as `(x, y)`.
+  pub fn position(&self, symbol: u8) -> Option<(usize, usize)> {
+    let index = self.bytes.iter().position(|byte| *byte == symbol)?;
+
+    Some((index % (self.width + 1), index / (self.width + 1)))
+  }
+
+  // Reads every tile, pointing at the first one that could not be read.
+  pub fn cells<T, F>(&self, mut cell: F) -> Result<Vec<Vec<T>>>
+  where
+    F: FnMut(char) -> Result<T, &'static str>,
+  {
+    self
+      .rows()
+      .enumerate()
+      .map(|(y, row)| {
+        row
+          .iter()
+          .enumerate()
+          .map(|(x, byte)| cell(*byte as char).map_err(|expected| Error::parse(self.first + y, x + 1, 1, expected)))
+          .collect()
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Input;
+
+  #[test]
+  fn normalise() {
+    let input = Input::new("ab\r\ncd\r\n\r\n");
+
+    assert_eq!(input.text(), "ab\ncd");
+    assert_eq!(input.lines().map(|line| (line.number, line.text)).collect::<Vec<_>>(), [(1, "ab"), (2, "cd")]);
+    assert_eq!(input.bytes().collect::<Vec<_>>(), [b"ab", b"cd"]);
+    assert_eq!(Input::new("").lines().count(), 0);
+  }
+
+  #[test]
+  fn blocks() {
+    let input = Input::new("\n#.\n.#\n\n\n..\n##\n");
+    let blocks = input.blocks().collect::<Vec<_>>();
+
+    assert_eq!(blocks.iter().map(|block| block.text).collect::<Vec<_>>(), ["#.\n.#", "..\n##"]);
+    assert_eq!(blocks[1].lines().map(|line| line.number).collect::<Vec<_>>(), [6, 7]);
+  }
+
+  #[test]
+  fn grid() {
+    let input = Input::new("#.S\n..#");
+    let grid = input.grid().unwrap();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.get(2, 1), Some(b'#'));
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.position(b'S'), Some((2, 0)));
+    assert_eq!(grid.cells(|tile| Ok(tile == '#')).unwrap(), [[true, false, false], [false, false, true]]);
+    assert_eq!(grid.cells(|_| Err::<bool, _>("a tile")).unwrap_err().to_string(), "<input>:1:1: expected a tile");
+    assert_eq!(Input::new("#.\n#").grid().unwrap_err().to_string(), "<input>:2:1: expected a row of 2 tiles");
+  }
+}
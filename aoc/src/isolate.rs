@@ -0,0 +1,248 @@
+use std::{
+  cell::{Cell, RefCell},
+  env,
+  io::{Read, Write},
+  panic::{self, AssertUnwindSafe},
+  path::Path,
+  process::{self, Command, ExitStatus, Stdio},
+  sync::Once,
+  thread,
+  time::Duration,
+};
+
+use rayon::ThreadPoolBuilder;
+use wait_timeout::ChildExt;
+
+use crate::{
+  memory::Bytes,
+  output::{Outcome, Record},
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Limits {
+  pub timeout: Option<Duration>,
+  // In bytes.
+  pub memory: Option<u64>,
+}
+
+impl Limits {
+  // Limits can only be enforced on a separate process, a thread that is stuck
+  // in a loop cannot be stopped from the outside.
+  pub fn isolated(&self) -> bool {
+    self.timeout.is_some() || self.memory.is_some()
+  }
+}
+
+thread_local! {
+  static CATCHING: Cell<bool> = const { Cell::new(false) };
+  static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// Runs the closure, turning a panic into its message and location. Panics are
+// then reported once, in the status row of the part, instead of also being
+// dumped to stderr, those happening anywhere else still going to the previous
+// hook.
+pub fn catch<F, T>(f: F) -> Result<T, String>
+where
+  F: FnOnce() -> T,
+{
+  static HOOK: Once = Once::new();
+
+  HOOK.call_once(|| {
+    let previous = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+      if !CATCHING.get() {
+        return previous(info);
+      }
+
+      let location = info.location().map(|location| format!(" at {}:{}", location.file(), location.line())).unwrap_or_default();
+
+      PANIC.with(|panic| *panic.borrow_mut() = Some(format!("{}{location}", message(info.payload()))));
+    }))
+  });
+
+  let catching = CATCHING.replace(true);
+  let result = panic::catch_unwind(AssertUnwindSafe(f));
+
+  CATCHING.set(catching);
+
+  result.map_err(|payload| PANIC.with(|panic| panic.borrow_mut().take()).unwrap_or_else(|| message(payload.as_ref())))
+}
+
+fn message(payload: &(dyn std::any::Any + Send)) -> String {
+  match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+    (Some(message), _) => message.to_string(),
+    (_, Some(message)) => message.clone(),
+    _ => "unknown panic".to_string(),
+  }
+}
+
+// Set on the children run with a memory limit.
+const LIMITED: &str = "AOC_MEMORY_LIMIT";
+
+// Exit status of a child that could not start its threads within its memory
+// limit.
+const NO_THREADS: i32 = 70;
+
+// Starts the thread pool upfront in children that have a memory limit, so that
+// a limit too low for the stacks of its threads is reported as such, rather
+// than as a panic of whichever part first solves something in parallel.
+pub fn prepare() {
+  if env::var_os(LIMITED).is_some() && ThreadPoolBuilder::new().build_global().is_err() {
+    process::exit(NO_THREADS);
+  }
+}
+
+// Runs the binary itself with the given arguments, which are expected to ask
+// for JSON output, and reads back the records it produced. The error is the
+// outcome to report when the process did not get to produce any.
+pub fn spawn(arguments: &[String], stdin: Option<&str>, limits: &Limits) -> Result<Vec<Record>, (Outcome, String)> {
+  let executable = env::current_exe().map_err(|error| (Outcome::Crashed, error.to_string()))?;
+
+  spawn_with(&executable, arguments, stdin, limits)
+}
+
+// Same as `spawn`, with another build of the binary.
+pub fn spawn_with(executable: &Path, arguments: &[String], stdin: Option<&str>, limits: &Limits) -> Result<Vec<Record>, (Outcome, String)> {
+  let crashed = |error: std::io::Error| (Outcome::Crashed, error.to_string());
+
+  let mut command = Command::new(executable);
+
+  command.args(arguments).stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped());
+
+  #[cfg(unix)]
+  if let Some(bytes) = limits.memory {
+    use std::os::unix::process::CommandExt;
+
+    // Only private writable memory is accounted for by RLIMIT_DATA, so that
+    // the shared libraries and allocator arenas mapped by the process do not
+    // count against the limit. The stacks of its threads do, which is why the
+    // child starts them upfront.
+    #[cfg(target_os = "linux")]
+    let resource = libc::RLIMIT_DATA;
+    #[cfg(not(target_os = "linux"))]
+    let resource = libc::RLIMIT_AS;
+
+    let limit = libc::rlimit {
+      rlim_cur: bytes as libc::rlim_t,
+      rlim_max: bytes as libc::rlim_t,
+    };
+
+    command.env(LIMITED, bytes.to_string());
+
+    // SAFETY: setrlimit is async-signal-safe and the closure does not allocate.
+    unsafe {
+      command.pre_exec(move || match libc::setrlimit(resource, &limit) {
+        0 => Ok(()),
+        _ => Err(std::io::Error::last_os_error()),
+      });
+    }
+  }
+
+  let mut child = command.spawn().map_err(crashed)?;
+
+  // Pipes are drained from separate threads so that a chatty child cannot
+  // block on a full pipe while it is waited for.
+  let input = stdin.map(String::from);
+  let mut child_stdin = child.stdin.take();
+  let writer = thread::spawn(move || {
+    if let (Some(input), Some(child_stdin)) = (input, child_stdin.as_mut()) {
+      let _ = child_stdin.write_all(input.as_bytes());
+    }
+  });
+
+  let reader = |pipe: Option<Box<dyn Read + Send>>| {
+    thread::spawn(move || {
+      let mut output = String::new();
+
+      if let Some(mut pipe) = pipe {
+        let _ = pipe.read_to_string(&mut output);
+      }
+
+      output
+    })
+  };
+
+  let stdout = reader(child.stdout.take().map(|pipe| Box::new(pipe) as Box<dyn Read + Send>));
+  let stderr = reader(child.stderr.take().map(|pipe| Box::new(pipe) as Box<dyn Read + Send>));
+
+  let status = match limits.timeout {
+    Some(timeout) => child.wait_timeout(timeout).map_err(crashed)?,
+    None => child.wait().map(Some).map_err(crashed)?,
+  };
+
+  if status.is_none() {
+    let _ = child.kill();
+    let _ = child.wait();
+  }
+
+  let _ = writer.join();
+  let (stdout, stderr) = (stdout.join().unwrap_or_default(), stderr.join().unwrap_or_default());
+
+  let Some(status) = status else {
+    return Err((Outcome::TimedOut, format!("took longer than {:?}", limits.timeout.unwrap_or_default())));
+  };
+
+  if let Ok(records) = serde_json::from_str::<Vec<Record>>(&stdout) {
+    eprint!("{stderr}");
+    return Ok(records);
+  }
+
+  // Allocation failures abort the process after printing a message, threads
+  // that cannot be started are reported by the child itself.
+  match limits.memory {
+    Some(bytes) if status.code() == Some(NO_THREADS) || aborted(&status) || stderr.contains("memory allocation of") => {
+      Err((Outcome::OutOfMemory, format!("exceeded the memory limit of {}", Bytes(bytes))))
+    }
+
+    _ => {
+      eprint!("{stderr}");
+      Err((Outcome::Crashed, status.to_string()))
+    }
+  }
+}
+
+#[cfg(unix)]
+fn aborted(status: &ExitStatus) -> bool {
+  use std::os::unix::process::ExitStatusExt;
+
+  status.signal() == Some(libc::SIGABRT)
+}
+
+#[cfg(not(unix))]
+fn aborted(_: &ExitStatus) -> bool {
+  false
+}
+
+// Accepts a number of bytes, optionally followed by a `K`, `M` or `G` binary
+// multiplier (e.g. 512M).
+pub fn parse_size(value: &str) -> Option<u64> {
+  let value = value.trim().trim_end_matches(['b', 'B']);
+
+  let (number, multiplier) = match value.char_indices().last()? {
+    (index, 'k' | 'K') => (&value[..index], 1 << 10),
+    (index, 'm' | 'M') => (&value[..index], 1 << 20),
+    (index, 'g' | 'G') => (&value[..index], 1 << 30),
+    _ => (value, 1),
+  };
+
+  number.trim().parse::<u64>().ok()?.checked_mul(multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+  #[test]
+  fn catch() {
+    assert!(super::catch(|| panic!("boom")).unwrap_err().starts_with("boom at src/isolate.rs:"));
+    assert!(!super::CATCHING.get());
+  }
+
+  #[test]
+  fn parse_size() {
+    assert_eq!(super::parse_size("4096"), Some(4096));
+    assert_eq!(super::parse_size("512M"), Some(512 << 20));
+    assert_eq!(super::parse_size("2GB"), Some(2 << 30));
+    assert_eq!(super::parse_size("lots"), None);
+  }
+}
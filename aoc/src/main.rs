@@ -0,0 +1,56 @@
+#![allow(dead_code)]
+
+extern crate aoc_macros as aoc;
+
+mod answer;
+mod batch;
+mod bench;
+mod check;
+mod cli;
+mod client;
+mod day;
+mod days;
+mod error;
+mod history;
+mod identify;
+mod input;
+mod isolate;
+mod memory;
+mod options;
+mod output;
+mod repl;
+mod runner;
+mod scaffold;
+#[cfg(test)]
+mod testing;
+mod watch;
+#[macro_use]
+mod util;
+
+use std::env;
+
+use aoc_macros::generate_days;
+
+use crate::cli::{Cli, Command};
+
+fn main() {
+  isolate::prepare();
+
+  let solutions = generate_days!();
+  let cli = Cli::parse(env::args().collect());
+
+  if let Some(dir) = &cli.data_dir {
+    util::set_data_dir(dir);
+  }
+
+  match cli.command {
+    Command::Run => runner::run(&cli, &solutions),
+    Command::Compare => history::run(&cli),
+    Command::New => scaffold::run(&cli, &solutions),
+    Command::Batch => batch::run(&cli, &solutions),
+    Command::Repl => repl::run(&cli, &solutions),
+    Command::Fetch => client::fetch(&cli, &solutions),
+    Command::Submit => client::submit(&cli, &solutions),
+    Command::Identify => identify::run(&cli, &solutions),
+  }
+}
@@ -0,0 +1,170 @@
+use std::{
+  alloc::{GlobalAlloc, Layout, System},
+  fmt,
+  sync::atomic::{AtomicU64, Ordering::Relaxed},
+};
+
+use serde::{Deserialize, Serialize};
+
+// Counting is opt-in, with the `count-allocations` feature, so that regular
+// runs keep the system allocator untouched.
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+// The system allocator, keeping track of how many allocations were made, how
+// many bytes they requested and how many bytes are live at most. Reallocations
+// count as new allocations of their new size.
+#[cfg_attr(not(feature = "count-allocations"), allow(dead_code))]
+pub struct Counting;
+
+impl Counting {
+  fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    ALLOCATED.fetch_add(size as u64, Relaxed);
+
+    let live = LIVE.fetch_add(size as u64, Relaxed) + size as u64;
+
+    PEAK.fetch_max(live, Relaxed);
+  }
+
+  fn freed(size: usize) {
+    LIVE.fetch_sub(size as u64, Relaxed);
+  }
+}
+
+// SAFETY: all the allocations are made by the system allocator, only counting
+// is added around it.
+unsafe impl GlobalAlloc for Counting {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let pointer = System.alloc(layout);
+
+    if !pointer.is_null() {
+      Counting::allocated(layout.size());
+    }
+
+    pointer
+  }
+
+  unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+    let pointer = System.alloc_zeroed(layout);
+
+    if !pointer.is_null() {
+      Counting::allocated(layout.size());
+    }
+
+    pointer
+  }
+
+  unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+    System.dealloc(pointer, layout);
+    Counting::freed(layout.size());
+  }
+
+  unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, size: usize) -> *mut u8 {
+    let reallocated = System.realloc(pointer, layout, size);
+
+    if !reallocated.is_null() {
+      Counting::freed(layout.size());
+      Counting::allocated(size);
+    }
+
+    reallocated
+  }
+}
+
+// What solving a part allocated. The peak is the most bytes that were live at
+// once on top of what already was, such as the parsed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Usage {
+  pub allocations: u64,
+  #[serde(rename = "allocated_bytes")]
+  pub allocated: u64,
+  #[serde(rename = "peak_bytes")]
+  pub peak: u64,
+}
+
+impl fmt::Display for Usage {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} allocations, {} allocated, {} peak", self.allocations, Bytes(self.allocated), Bytes(self.peak))
+  }
+}
+
+pub struct Bytes(pub u64);
+
+impl fmt::Display for Bytes {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let units = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let (mut size, mut unit) = (self.0 as f64, 0);
+
+    while size >= 1024.0 && unit < units.len() - 1 {
+      size /= 1024.0;
+      unit += 1;
+    }
+
+    match unit {
+      0 => write!(f, "{} B", self.0),
+      _ => write!(f, "{size:.1} {}", units[unit]),
+    }
+  }
+}
+
+// Measures what the closure allocates, when allocations are counted at all.
+// Counters are shared by the whole process, so parts measured at the same time
+// on several threads see each other's allocations, unless `--uncontended`.
+pub fn measure<T, F>(f: F) -> (T, Option<Usage>)
+where
+  F: FnOnce() -> T,
+{
+  if !cfg!(feature = "count-allocations") {
+    return (f(), None);
+  }
+
+  let (allocations, allocated, live) = (ALLOCATIONS.load(Relaxed), ALLOCATED.load(Relaxed), LIVE.load(Relaxed));
+
+  PEAK.store(live, Relaxed);
+
+  let value = f();
+
+  let usage = Usage {
+    allocations: ALLOCATIONS.load(Relaxed) - allocations,
+    allocated: ALLOCATED.load(Relaxed) - allocated,
+    peak: PEAK.load(Relaxed).saturating_sub(live),
+  };
+
+  (value, Some(usage))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Bytes, Usage};
+
+  #[test]
+  fn display() {
+    assert_eq!(Bytes(512).to_string(), "512 B");
+    assert_eq!(Bytes(3 * 1024 * 1024 / 2).to_string(), "1.5 MiB");
+
+    let usage = Usage {
+      allocations: 12,
+      allocated: 2048,
+      peak: 1024,
+    };
+
+    assert_eq!(usage.to_string(), "12 allocations, 2.0 KiB allocated, 1.0 KiB peak");
+  }
+
+  #[cfg(feature = "count-allocations")]
+  #[test]
+  fn measure() {
+    let (_, usage) = super::measure(|| vec![0u8; 4096]);
+    let usage = usage.unwrap();
+
+    assert!(usage.allocations >= 1);
+    assert!(usage.allocated >= 4096);
+  }
+}
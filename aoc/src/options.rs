@@ -0,0 +1,204 @@
+use std::{
+  collections::BTreeMap,
+  io,
+  ops::Range,
+  path::{Path, PathBuf},
+};
+
+use toml::{Spanned, Value};
+
+use crate::{
+  error::{Error, Result},
+  util,
+};
+
+// Options of a day, such as the number of steps to walk, declared with
+// `crate::options!`. Their defaults can be overridden by name, from a defaults
+// file next to the inputs and from the command line.
+pub trait Options: Default + Send + Sync + 'static {
+  fn describe() -> Vec<Description>;
+
+  // The error is what was expected instead.
+  fn set(&mut self, name: &str, value: &str) -> std::result::Result<(), String>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Description {
+  pub name: &'static str,
+  pub default: String,
+  pub help: &'static str,
+}
+
+impl Options for () {
+  fn describe() -> Vec<Description> {
+    vec![]
+  }
+
+  fn set(&mut self, _name: &str, _value: &str) -> std::result::Result<(), String> {
+    Err("no option, this day does not take any".to_string())
+  }
+}
+
+// Declares the options of a day, each with its type, default and description,
+// the types being parsed with `FromStr`:
+//
+//   crate::options! {
+//     pub struct Day21Options {
+//       steps: usize = 64 => "number of steps to walk",
+//     }
+//   }
+#[macro_export]
+macro_rules! options {
+  ($vis:vis struct $name:ident { $($field:ident: $type:ty = $default:expr => $help:literal),* $(,)? }) => {
+    #[derive(Debug, Clone, PartialEq)]
+    $vis struct $name {
+      $(pub $field: $type,)*
+    }
+
+    impl Default for $name {
+      fn default() -> Self {
+        $name { $($field: $default,)* }
+      }
+    }
+
+    impl $crate::options::Options for $name {
+      fn describe() -> Vec<$crate::options::Description> {
+        let defaults = Self::default();
+
+        vec![$($crate::options::Description {
+          name: stringify!($field),
+          default: defaults.$field.to_string(),
+          help: $help,
+        }),*]
+      }
+
+      fn set(&mut self, name: &str, value: &str) -> std::result::Result<(), String> {
+        $(
+          if name == stringify!($field) {
+            self.$field = value.trim().parse::<$type>().map_err(|_| format!("a valid `{}` for `{name}`", stringify!($type)))?;
+
+            return Ok(());
+          }
+        )*
+
+        Err(format!("one of {}", [$(concat!("`", stringify!($field), "`")),*].join(", ")))
+      }
+    }
+  };
+}
+
+// An option set by name, along with where it was set in the defaults file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting {
+  pub name: String,
+  pub value: String,
+  pub span: Option<Range<usize>>,
+}
+
+impl Setting {
+  // Parses `name=value`, as given on the command line.
+  pub fn parse(text: &str) -> Option<Setting> {
+    let (name, value) = text.split_once('=')?;
+
+    Some(Setting {
+      name: name.trim().to_string(),
+      value: value.trim().to_string(),
+      span: None,
+    })
+  }
+}
+
+// Defaults are kept next to the inputs, in a flat TOML file per day and kind
+// of input, since examples usually need smaller values:
+//
+//   # data/2023/21_test_options.toml
+//   steps = 6
+pub fn defaults_file(year: usize, day: usize, test: bool) -> PathBuf {
+  match test {
+    true => util::year_dir(year).join(format!("{day:0>2}_test_options.toml")),
+    false => util::year_dir(year).join(format!("{day:0>2}_options.toml")),
+  }
+}
+
+// Reads the defaults file, which is optional, along with its content so that
+// errors can be located in it.
+pub fn load_defaults(file: &Path) -> Result<(String, Vec<Setting>)> {
+  let content = match util::read_data(file) {
+    Ok(content) => content,
+    Err(source) if source.kind() == io::ErrorKind::NotFound => return Ok((String::new(), vec![])),
+    Err(source) => {
+      return Err(Error::Io {
+        file: util::source_name(file),
+        source,
+      })
+    }
+  };
+
+  let settings = parse_defaults(&content)?;
+
+  Ok((content, settings))
+}
+
+pub fn parse_defaults(content: &str) -> Result<Vec<Setting>> {
+  let table =
+    toml::from_str::<BTreeMap<Spanned<String>, Spanned<Value>>>(content).map_err(|err| Error::at_offset(content, err.span().unwrap_or(0..0), format!("valid TOML ({})", err.message().trim())))?;
+
+  table
+    .into_iter()
+    .map(|(name, value)| {
+      let text = match value.get_ref() {
+        Value::String(text) => text.clone(),
+        Value::Integer(number) => number.to_string(),
+        Value::Float(number) => number.to_string(),
+        Value::Boolean(flag) => flag.to_string(),
+        _ => return Err(Error::at_offset(content, value.span(), "a string, a number or a boolean")),
+      };
+
+      Ok(Setting {
+        name: name.get_ref().clone(),
+        value: text,
+        span: Some(name.span()),
+      })
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Options, Setting};
+  use crate::error::Error;
+
+  crate::options! {
+    struct Garden {
+      steps: usize = 64 => "number of steps to walk",
+      label: String = "garden".to_string() => "name of the garden",
+    }
+  }
+
+  #[test]
+  fn set() {
+    let mut garden = Garden::default();
+
+    assert_eq!(Garden::describe()[0].default, "64");
+    assert!(garden.set("steps", "6").is_ok());
+    assert_eq!(garden.steps, 6);
+    assert_eq!(garden.set("steps", "six"), Err("a valid `usize` for `steps`".to_string()));
+    assert_eq!(garden.set("stride", "6"), Err("one of `steps`, `label`".to_string()));
+    assert!(().set("steps", "6").is_err());
+  }
+
+  #[test]
+  fn defaults() {
+    let settings = super::parse_defaults("steps = 6\nlabel = \"small\"\n").unwrap();
+
+    assert_eq!(
+      settings.iter().map(|setting| (setting.name.as_str(), setting.value.as_str())).collect::<Vec<_>>(),
+      [("label", "small"), ("steps", "6")]
+    );
+    assert_eq!(Setting::parse("steps=26501365").map(|setting| setting.value), Some("26501365".to_string()));
+
+    let Err(Error::Parse(error)) = super::parse_defaults("steps = [6]\n") else { panic!() };
+
+    assert_eq!((error.line, error.column), (1, 9));
+  }
+}
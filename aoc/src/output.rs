@@ -0,0 +1,344 @@
+use std::{fmt, str::FromStr, time::Duration};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  answer::Answer,
+  bench::Stats,
+  check::Status,
+  memory::{Bytes, Usage},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+  Text,
+  Json,
+  Csv,
+  Markdown,
+}
+
+impl FromStr for Format {
+  type Err = String;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    match value {
+      "text" => Ok(Format::Text),
+      "json" => Ok(Format::Json),
+      "csv" => Ok(Format::Csv),
+      "markdown" | "md" => Ok(Format::Markdown),
+      _ => Err(format!("unknown format `{value}`, expected one of `text`, `json`, `csv` or `markdown`")),
+    }
+  }
+}
+
+// How running a part went. Parts that could not be solved come without an
+// answer, and usually with a message explaining why.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+  Solved,
+  InvalidInput,
+  Panicked,
+  TimedOut,
+  OutOfMemory,
+  Crashed,
+}
+
+impl fmt::Display for Outcome {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Outcome::Solved => write!(f, "solved"),
+      Outcome::InvalidInput => write!(f, "invalid input"),
+      Outcome::Panicked => write!(f, "panicked"),
+      Outcome::TimedOut => write!(f, "timed out"),
+      Outcome::OutOfMemory => write!(f, "out of memory"),
+      Outcome::Crashed => write!(f, "crashed"),
+    }
+  }
+}
+
+// Durations are serialized as integer nanoseconds, and the steps that did not
+// happen for a part (reading and parsing an input shared with the previous
+// part) as null, so that records keep the same shape across runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+  pub year: usize,
+  pub day: usize,
+  pub part: usize,
+  pub outcome: Outcome,
+  pub answer: Option<Answer>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub message: Option<String>,
+  #[serde(rename = "read_ns", with = "optional_nanos")]
+  pub read: Option<Duration>,
+  #[serde(rename = "parse_ns", with = "optional_nanos")]
+  pub parse: Option<Duration>,
+  #[serde(rename = "solve_ns", with = "optional_nanos")]
+  pub solve: Option<Duration>,
+  pub input: String,
+  pub test: bool,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub memory: Option<Usage>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub bench: Option<Stats>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub check: Option<Status>,
+}
+
+pub mod nanos {
+  use std::time::Duration;
+
+  use serde::{Deserialize, Deserializer, Serializer};
+
+  pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+  }
+
+  pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    u64::deserialize(deserializer).map(Duration::from_nanos)
+  }
+}
+
+mod optional_nanos {
+  use std::time::Duration;
+
+  use serde::{Deserialize, Deserializer, Serializer};
+
+  pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match duration {
+      Some(duration) => super::nanos::serialize(duration, serializer),
+      None => serializer.serialize_none(),
+    }
+  }
+
+  pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    Option::<u64>::deserialize(deserializer).map(|nanos| nanos.map(Duration::from_nanos))
+  }
+}
+
+impl Record {
+  pub fn failed(year: usize, day: usize, part: usize, outcome: Outcome, message: Option<String>, input: String, test: bool) -> Record {
+    Record {
+      year,
+      day,
+      part,
+      outcome,
+      answer: None,
+      message,
+      read: None,
+      parse: None,
+      solve: None,
+      input,
+      test,
+      memory: None,
+      bench: None,
+      check: None,
+    }
+  }
+
+  pub fn steps(&self) -> Vec<(&'static str, Duration)> {
+    [("read", self.read), ("parse", self.parse), ("solve", self.solve)]
+      .into_iter()
+      .filter_map(|(step, duration)| Some((step, duration?)))
+      .collect()
+  }
+
+  fn time(&self) -> Duration {
+    self.steps().into_iter().map(|(_, duration)| duration).sum()
+  }
+
+  // The answer, or what prevented getting one.
+  pub fn result(&self) -> String {
+    match (&self.answer, &self.message) {
+      (Some(answer), _) => answer.to_string(),
+      (None, Some(message)) => format!("{}: {message}", self.outcome),
+      (None, None) => self.outcome.to_string(),
+    }
+  }
+
+  pub fn text(&self, timings: bool) -> String {
+    let mut line = format!("{} D{:0>2}P{:0>2}: {} ", self.year, self.day, self.part, self.result());
+
+    if timings && self.answer.is_some() {
+      let steps = self.steps().iter().map(|(step, duration)| format!("{step}: {duration:?}")).join(", ");
+
+      match &self.memory {
+        Some(memory) => line.push_str(&format!("({steps}; {memory}) ")),
+        None => line.push_str(&format!("({steps}) ")),
+      }
+    }
+
+    match &self.check {
+      Some(Status::Pass) => line.push_str("[pass]"),
+      Some(Status::Fail { expected }) => line.push_str(&format!("[FAIL, expected {expected}]")),
+      Some(Status::Missing) => line.push_str("[missing]"),
+      None => {}
+    }
+
+    line.trim_end().to_string()
+  }
+}
+
+// Renders all the records at once, for the formats that cannot be streamed.
+pub fn render(format: Format, records: &[Record]) -> String {
+  match format {
+    Format::Text => records.iter().map(|record| record.text(false)).join("\n"),
+    Format::Json => serde_json::to_string_pretty(records).unwrap_or_default(),
+    Format::Csv => csv(records),
+    Format::Markdown => markdown(records),
+  }
+}
+
+fn csv(records: &[Record]) -> String {
+  let mut writer = csv::Writer::from_writer(vec![]);
+  let nanos = |duration: Option<Duration>| duration.map(|duration| duration.as_nanos().to_string()).unwrap_or_default();
+  let memory = |record: &Record, count: fn(&Usage) -> u64| record.memory.as_ref().map(|memory| count(memory).to_string()).unwrap_or_default();
+
+  let _ = writer.write_record([
+    "year",
+    "day",
+    "part",
+    "outcome",
+    "answer",
+    "read_ns",
+    "parse_ns",
+    "solve_ns",
+    "allocations",
+    "allocated_bytes",
+    "peak_bytes",
+    "input",
+    "test",
+  ]);
+
+  for record in records {
+    let _ = writer.write_record([
+      record.year.to_string(),
+      record.day.to_string(),
+      record.part.to_string(),
+      serde_json::to_value(record.outcome).ok().and_then(|outcome| outcome.as_str().map(String::from)).unwrap_or_default(),
+      record.answer.as_ref().map(ToString::to_string).unwrap_or_default(),
+      nanos(record.read),
+      nanos(record.parse),
+      nanos(record.solve),
+      memory(record, |memory| memory.allocations),
+      memory(record, |memory| memory.allocated),
+      memory(record, |memory| memory.peak),
+      record.input.clone(),
+      record.test.to_string(),
+    ]);
+  }
+
+  writer.into_inner().ok().and_then(|bytes| String::from_utf8(bytes).ok()).unwrap_or_default().trim_end().to_string()
+}
+
+fn markdown(records: &[Record]) -> String {
+  let escape = |cell: String| cell.replace('|', "\\|");
+
+  // Memory columns only show up when allocations were counted.
+  let counted = records.iter().any(|record| record.memory.is_some());
+  let mut lines = match counted {
+    true => vec![
+      "| Year | Day | Part | Answer | Time | Allocations | Peak | Input | Test |".to_string(),
+      "| ---: | --: | ---: | -----: | ---: | ----------: | ---: | :---- | :--: |".to_string(),
+    ],
+    false => vec![
+      "| Year | Day | Part | Answer | Time | Input | Test |".to_string(),
+      "| ---: | --: | ---: | -----: | ---: | :---- | :--: |".to_string(),
+    ],
+  };
+
+  for record in records {
+    let memory = match (counted, &record.memory) {
+      (true, Some(memory)) => format!(" {} | {} |", memory.allocations, Bytes(memory.peak)),
+      (true, None) => " | |".to_string(),
+      (false, _) => String::new(),
+    };
+
+    lines.push(format!(
+      "| {} | {} | {} | {} | {:.2?} |{memory} {} | {} |",
+      record.year,
+      record.day,
+      record.part,
+      escape(match &record.answer {
+        Some(answer) => answer.to_string(),
+        None => format!("*{}*", record.result()),
+      }),
+      record.time(),
+      escape(record.input.clone()),
+      if record.test { "yes" } else { "no" }
+    ));
+  }
+
+  lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use super::{Format, Outcome, Record};
+  use crate::memory::Usage;
+
+  fn record() -> Record {
+    Record {
+      answer: Some(46.into()),
+      solve: Some(Duration::from_nanos(1500)),
+      ..Record::failed(2023, 5, 2, Outcome::Solved, None, "data/2023/05_test.txt".to_string(), true)
+    }
+  }
+
+  #[test]
+  fn json() {
+    assert_eq!(
+      super::render(Format::Json, &[record()]),
+      "[\n  {\n    \"year\": 2023,\n    \"day\": 5,\n    \"part\": 2,\n    \"outcome\": \"solved\",\n    \"answer\": 46,\n    \"read_ns\": null,\n    \"parse_ns\": null,\n    \"solve_ns\": 1500,\n    \"input\": \"data/2023/05_test.txt\",\n    \"test\": true\n  }\n]"
+    );
+  }
+
+  #[test]
+  fn csv() {
+    assert_eq!(
+      super::render(Format::Csv, &[record()]),
+      "year,day,part,outcome,answer,read_ns,parse_ns,solve_ns,allocations,allocated_bytes,peak_bytes,input,test\n2023,5,2,solved,46,,,1500,,,,data/2023/05_test.txt,true"
+    );
+  }
+
+  #[test]
+  fn failures() {
+    let record = Record::failed(2023, 14, 2, Outcome::Panicked, Some("should not be reached".to_string()), "data/2023/14.txt".to_string(), false);
+
+    assert_eq!(record.text(true), "2023 D14P02: panicked: should not be reached");
+    assert!(super::render(Format::Csv, &[record]).ends_with("2023,14,2,panicked,,,,,,,,data/2023/14.txt,false"));
+  }
+
+  #[test]
+  fn memory() {
+    let record = Record {
+      memory: Some(Usage {
+        allocations: 3,
+        allocated: 4096,
+        peak: 2048,
+      }),
+      ..record()
+    };
+
+    assert_eq!(record.text(true), "2023 D05P02: 46 (solve: 1.5µs; 3 allocations, 4.0 KiB allocated, 2.0 KiB peak)");
+    let records = [record];
+
+    assert!(super::render(Format::Json, &records).contains("\"peak_bytes\": 2048"));
+    assert!(super::render(Format::Markdown, &records).ends_with("| 2023 | 5 | 2 | 46 | 1.50µs | 3 | 2.0 KiB | data/2023/05_test.txt | yes |"));
+  }
+}
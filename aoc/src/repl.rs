@@ -0,0 +1,361 @@
+use std::{
+  collections::HashMap,
+  fs,
+  path::{Path, PathBuf},
+  process,
+  time::{Duration, Instant, SystemTime},
+};
+
+use rustyline::error::ReadlineError;
+
+use crate::{
+  cli::{fail, Cli},
+  day::{Parsed, Solution},
+  isolate, memory,
+  options::Setting,
+  output::{Outcome, Record},
+  runner, util,
+};
+
+pub const HELP: &str = "\
+run DAY [PART]     solve a day, or one of its parts
+run                solve the last day again
+year YEAR          switch to another year
+set NAME VALUE     set an option, over the defaults of the input
+unset NAME         go back to the default of an option
+options            list the options of the last day, and those set
+input test|real    run on the examples or on the puzzle inputs
+input FILE         run on the given file
+time               show or hide the timings
+history            list the runs of this session
+help               show this help
+quit               leave, as does Ctrl-D";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+  Test,
+  Real,
+  File(PathBuf),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+  Run(Option<(usize, Option<usize>)>),
+  Year(usize),
+  Set(Setting),
+  Unset(String),
+  Options,
+  Input(Source),
+  Time,
+  History,
+  Help,
+  Quit,
+}
+
+impl Command {
+  // Blank lines are no command at all, errors are what was expected.
+  pub fn parse(line: &str) -> Result<Option<Command>, String> {
+    let words = line.split_whitespace().collect::<Vec<_>>();
+    let number = |word: &str, what: &str| word.parse::<usize>().map_err(|_| format!("a {what}, got `{word}`"));
+
+    let command = match words.as_slice() {
+      [] => return Ok(None),
+      ["run"] => Command::Run(None),
+      ["run", day] => Command::Run(Some((number(day, "day")?, None))),
+      ["run", day, part] => Command::Run(Some((number(day, "day")?, Some(number(part, "part")?)))),
+      ["year", year] => Command::Year(number(year, "year")?),
+      ["set", setting] => Command::Set(Setting::parse(setting).ok_or("`set NAME VALUE`")?),
+      ["set", name, value @ ..] if !value.is_empty() => Command::Set(Setting {
+        name: name.to_string(),
+        value: value.join(" "),
+        span: None,
+      }),
+      ["unset", name] => Command::Unset(name.to_string()),
+      ["options"] => Command::Options,
+      ["input", "test"] => Command::Input(Source::Test),
+      ["input", "real"] => Command::Input(Source::Real),
+      ["input", file] => Command::Input(Source::File(PathBuf::from(file))),
+      ["time"] => Command::Time,
+      ["history"] => Command::History,
+      ["help"] => Command::Help,
+      ["quit" | "exit"] => Command::Quit,
+      [command, ..] => return Err(format!("a command, got `{command}`, see `help`")),
+    };
+
+    Ok(Some(command))
+  }
+}
+
+// Parsed inputs, along with when their file was last modified so that they are
+// parsed again once it changes.
+type Cache = HashMap<(usize, usize, PathBuf), (Option<SystemTime>, Parsed)>;
+
+pub struct Session<'a> {
+  solutions: &'a [Solution],
+  year: usize,
+  last: Option<(usize, Option<usize>)>,
+  overrides: Vec<Setting>,
+  source: Source,
+  timings: bool,
+  cache: Cache,
+  runs: Vec<Record>,
+}
+
+impl<'a> Session<'a> {
+  pub fn new(solutions: &'a [Solution], year: usize) -> Session<'a> {
+    Session {
+      solutions,
+      year,
+      last: None,
+      overrides: vec![],
+      source: Source::Real,
+      timings: true,
+      cache: Cache::new(),
+      runs: vec![],
+    }
+  }
+
+  pub fn prompt(&self) -> String {
+    let input = match &self.source {
+      Source::Test => "test".to_string(),
+      Source::Real => "real".to_string(),
+      Source::File(file) => file.display().to_string(),
+    };
+
+    match self.last {
+      Some((day, _)) => format!("{} day {day} ({input})> ", self.year),
+      None => format!("{} ({input})> ", self.year),
+    }
+  }
+
+  fn solution(&self, day: usize) -> Option<&'a Solution> {
+    self.solutions.iter().find(|solution| solution.year == self.year && solution.day == day)
+  }
+
+  // Runs a command, and returns what to print. Quitting is left to the caller.
+  pub fn execute(&mut self, command: Command) -> Vec<String> {
+    match command {
+      Command::Run(selection) => match selection.or(self.last) {
+        Some((day, part)) => self.run(day, part),
+        None => vec!["Nothing to run again yet, use `run DAY [PART]`.".to_string()],
+      },
+
+      Command::Year(year) => match self.solutions.iter().any(|solution| solution.year == year) {
+        true => {
+          self.year = year;
+          self.last = None;
+          vec![]
+        }
+
+        false => vec!["Unknown year, come back later! o7".to_string()],
+      },
+
+      Command::Set(setting) => {
+        let known = self.last.and_then(|(day, _)| self.solution(day)).map(|solution| solution.describe());
+
+        if known.is_some_and(|known| !known.iter().any(|option| option.name == setting.name)) {
+          return vec![format!("Unknown option `{}`, see `options`.", setting.name)];
+        }
+
+        self.overrides.retain(|other| other.name != setting.name);
+        self.overrides.push(setting);
+        vec![]
+      }
+
+      Command::Unset(name) => {
+        self.overrides.retain(|other| other.name != name);
+        vec![]
+      }
+
+      Command::Options => self.options(),
+
+      Command::Input(source) => {
+        self.source = source;
+        vec![]
+      }
+
+      Command::Time => {
+        self.timings = !self.timings;
+        vec![format!("Timings are {}.", if self.timings { "shown" } else { "hidden" })]
+      }
+
+      Command::History => self
+        .runs
+        .iter()
+        .enumerate()
+        .map(|(index, record)| format!("{:>3}  {} [{}]", index + 1, record.text(true), record.input))
+        .collect(),
+      Command::Help => HELP.lines().map(String::from).collect(),
+      Command::Quit => vec![],
+    }
+  }
+
+  fn options(&self) -> Vec<String> {
+    let known = self.last.and_then(|(day, _)| self.solution(day)).map(|solution| solution.describe()).unwrap_or_default();
+
+    let mut lines = known
+      .iter()
+      .map(|option| format!("{} = {} ({}, default {})", option.name, self.value(option.name), option.help, option.default))
+      .collect::<Vec<_>>();
+
+    let others = self.overrides.iter().filter(|setting| !known.iter().any(|option| option.name == setting.name));
+
+    lines.extend(others.map(|setting| format!("{} = {} (not an option of the last day)", setting.name, setting.value)));
+
+    match lines.is_empty() {
+      true => vec!["No option is set, and the last day run takes none.".to_string()],
+      false => lines,
+    }
+  }
+
+  fn value(&self, name: &str) -> String {
+    match self.overrides.iter().find(|setting| setting.name == name) {
+      Some(setting) => setting.value.clone(),
+      None => "(default)".to_string(),
+    }
+  }
+
+  fn run(&mut self, day: usize, part: Option<usize>) -> Vec<String> {
+    let Some(solution) = self.solution(day) else {
+      return vec!["Unknown day, come back later! o7".to_string()];
+    };
+
+    let parts = match part {
+      Some(part) if !solution.has_part(part) => return vec![format!("Day {day} part {part} is not solved yet, come back later! o7")],
+      Some(part) => vec![part],
+      None => solution.parts(),
+    };
+
+    self.last = Some((day, part));
+
+    let test = self.source == Source::Test;
+    let configured = match runner::configure(solution, test, &self.overrides) {
+      Ok(configured) => configured,
+      Err(message) => return vec![message],
+    };
+
+    let mut output = vec![];
+
+    for part in parts {
+      let file = match &self.source {
+        Source::Test => util::input_file(self.year, day, part, true),
+        Source::Real => util::input_file(self.year, day, part, false),
+        Source::File(file) => file.clone(),
+      };
+
+      let name = util::source_name(&file);
+
+      let parse = match self.load(solution, &file) {
+        Ok(parse) => parse,
+        Err(error) => {
+          output.push(error);
+          self.runs.push(Record::failed(self.year, day, part, Outcome::InvalidInput, None, name, test));
+          continue;
+        }
+      };
+
+      let (year, cache) = (self.year, &self.cache);
+      let input = &cache[&(year, day, file.clone())].1;
+
+      let solved = isolate::catch(|| {
+        let before = Instant::now();
+        let (answer, memory) = memory::measure(|| solution.solve(part, input, &configured));
+
+        (answer, before.elapsed(), memory)
+      });
+
+      let record = match solved {
+        Ok((answer, solve, memory)) => Record {
+          answer: Some(answer),
+          parse,
+          solve: Some(solve),
+          memory,
+          ..Record::failed(year, day, part, Outcome::Solved, None, name, test)
+        },
+
+        Err(panic) => Record::failed(year, day, part, Outcome::Panicked, Some(panic), name, test),
+      };
+
+      output.push(record.text(self.timings));
+      self.runs.push(record);
+    }
+
+    output
+  }
+
+  // Makes sure the input is parsed and cached, and returns how long parsing it
+  // took when it was not already.
+  fn load(&mut self, solution: &Solution, file: &Path) -> Result<Option<Duration>, String> {
+    let key = (self.year, solution.day, file.to_path_buf());
+    let modified = fs::metadata(file).and_then(|metadata| metadata.modified()).ok();
+
+    if self.cache.get(&key).is_some_and(|(cached, _)| *cached == modified) {
+      return Ok(None);
+    }
+
+    let loaded = isolate::catch(|| runner::load(solution, file)).map_err(|panic| format!("{}: panicked: {panic}", util::source_name(file)))?;
+
+    match loaded {
+      Ok((parsed, (_, parse))) => {
+        self.cache.insert(key, (modified, parsed));
+        Ok(Some(parse))
+      }
+
+      Err((error, input)) => Err(error.in_file(&util::source_name(file)).render(input.text())),
+    }
+  }
+}
+
+// Reads commands until the end of the input, or `quit`.
+pub fn run(cli: &Cli, solutions: &[Solution]) -> ! {
+  let year = cli.year(solutions);
+
+  if !solutions.iter().any(|solution| solution.year == year) {
+    fail("Unknown year, come back later! o7");
+  }
+
+  let mut editor = rustyline::DefaultEditor::new().unwrap_or_else(|error| fail(format!("Could not start the session: {error}")));
+  let mut session = Session::new(solutions, year);
+
+  loop {
+    let line = match editor.readline(&session.prompt()) {
+      Ok(line) => line,
+      Err(ReadlineError::Interrupted) => continue,
+      Err(ReadlineError::Eof) => break,
+      Err(error) => fail(format!("Could not read the command: {error}")),
+    };
+
+    let _ = editor.add_history_entry(line.as_str());
+
+    match Command::parse(&line) {
+      Ok(Some(Command::Quit)) => break,
+      Ok(Some(command)) => {
+        for output in session.execute(command) {
+          println!("{output}");
+        }
+      }
+      Ok(None) => {}
+      Err(expected) => println!("Expected {expected}."),
+    }
+  }
+
+  process::exit(0);
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::PathBuf;
+
+  use super::{Command, Source};
+
+  #[test]
+  fn parse() {
+    assert_eq!(Command::parse("run 21 1"), Ok(Some(Command::Run(Some((21, Some(1)))))));
+    assert_eq!(Command::parse("  "), Ok(None));
+    assert_eq!(Command::parse("input data/2023/21.txt"), Ok(Some(Command::Input(Source::File(PathBuf::from("data/2023/21.txt"))))));
+    assert!(matches!(Command::parse("set steps 100"), Ok(Some(Command::Set(setting))) if setting.name == "steps" && setting.value == "100"));
+    assert!(matches!(Command::parse("set steps=100"), Ok(Some(Command::Set(setting))) if setting.value == "100"));
+    assert_eq!(Command::parse("run twenty"), Err("a day, got `twenty`".to_string()));
+    assert!(Command::parse("jump").is_err());
+  }
+}
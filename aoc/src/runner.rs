@@ -0,0 +1,535 @@
+use std::{
+  collections::BTreeMap,
+  fs, io, iter,
+  path::{Path, PathBuf},
+  process,
+  sync::{mpsc, Mutex},
+  thread,
+  time::{Duration, Instant},
+};
+
+use itertools::Itertools;
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
+
+use crate::{
+  bench,
+  check::{self, Answers},
+  cli::{fail, fail_in, Cli},
+  day::{Configured, Parsed, Solution},
+  error::{Error, Result},
+  history::{self, Run},
+  input::Input,
+  isolate::{self, Limits},
+  memory, options,
+  options::Setting,
+  output::{self, Format, Outcome, Record},
+  util::{self, trace},
+  watch,
+};
+
+pub struct Settings {
+  pub test: bool,
+  pub timings: bool,
+  // Overrides the input file of every requested part, `-` being stdin.
+  pub input: Option<PathBuf>,
+  pub bench: Option<bench::Config>,
+  pub format: Format,
+  // Known answers to compare against, when checking.
+  pub answers: Option<Answers>,
+  pub limits: Limits,
+  // Standard input, read upfront to be fed to every isolated part.
+  pub stdin: Option<String>,
+  // Size of the thread pool, when running days and parts concurrently.
+  pub jobs: Option<usize>,
+  // Options set on the command line, applied over the defaults of each day.
+  pub overrides: Vec<Setting>,
+  // Pool on which parts are measured when running concurrently, apart from the
+  // one running the days, as wide as it.
+  measuring: Option<ThreadPool>,
+  // Taken around each measurement, when measurements should not overlap.
+  uncontended: Option<Mutex<()>>,
+}
+
+impl Settings {
+  pub fn new(cli: &Cli) -> Settings {
+    let budget = cli
+      .budget
+      .as_ref()
+      .map(|budget| bench::parse_duration(budget).unwrap_or_else(|| fail(format!("Invalid time budget: {budget}"))));
+
+    let limits = Limits {
+      timeout: cli
+        .timeout
+        .as_ref()
+        .map(|timeout| bench::parse_duration(timeout).unwrap_or_else(|| fail(format!("Invalid timeout: {timeout}")))),
+      memory: cli
+        .memory_limit
+        .as_ref()
+        .map(|limit| isolate::parse_size(limit).unwrap_or_else(|| fail(format!("Invalid memory limit: {limit}")))),
+    };
+
+    let stdin = match limits.isolated() && cli.input.as_deref() == Some(util::STDIN) {
+      true => Some(io::read_to_string(io::stdin()).unwrap_or_default()),
+      false => None,
+    };
+
+    let jobs = cli.jobs.filter(|jobs| *jobs > 0);
+
+    Settings {
+      test: cli.test,
+      timings: cli.timings,
+      input: cli.input.as_ref().map(PathBuf::from),
+      bench: cli.bench.then_some(bench::Config {
+        warmup: cli.warmup,
+        iterations: cli.iterations,
+        budget,
+      }),
+      format: cli.format,
+      answers: None,
+      limits,
+      stdin,
+      jobs,
+      overrides: cli.overrides(),
+      measuring: jobs.map(|jobs| {
+        ThreadPoolBuilder::new()
+          .num_threads(jobs)
+          .build()
+          .unwrap_or_else(|error| fail(format!("Could not start the thread pool: {error}")))
+      }),
+      uncontended: (cli.uncontended && jobs.is_some()).then(Mutex::default),
+    }
+  }
+
+  // A thread of the pool running the days steals their jobs while it waits, so
+  // parts are not measured there, and neither is the lock taken there, as such
+  // a thread could take it again. Both happen on a thread of their own, which
+  // only blocks.
+  fn measure<T, F>(&self, f: F) -> T
+  where
+    T: Send,
+    F: FnOnce() -> T + Send,
+  {
+    let Some(pool) = &self.measuring else {
+      return f();
+    };
+
+    thread::scope(|scope| {
+      scope
+        .spawn(|| {
+          let _guard = self.uncontended.as_ref().map(|lock| lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner()));
+
+          pool.install(f)
+        })
+        .join()
+        .expect("measured parts catch their panics")
+    })
+  }
+}
+
+pub fn run(cli: &Cli, solutions: &[Solution]) -> ! {
+  if cli.verbose > 0 || cli.trace.is_some() {
+    let level = if cli.trace.is_some() { cli.verbose.max(trace::INFO) } else { cli.verbose };
+
+    if let Err(error) = trace::init(level, cli.trace.as_deref().map(Path::new)) {
+      fail(format!("Could not create {}: {error}", cli.trace.as_deref().unwrap_or_default()));
+    }
+  }
+
+  if cli.watch {
+    watch::run(cli);
+  }
+
+  if cli.input.is_some() && cli.day.is_none() {
+    fail("An explicit input can only be used when running a single day.");
+  }
+
+  let mut settings = Settings::new(cli);
+  let check = cli.check || cli.junit.is_some();
+
+  if check && cli.input.is_some() {
+    fail("Known answers only apply to the inputs from the data directory, --check cannot be used with --input.");
+  }
+
+  let selected: Vec<(&Solution, Vec<usize>)> = match (cli.year, cli.day) {
+    (None, _) => solutions.iter().map(|solution| (solution, solution.parts())).collect(),
+
+    (Some(year), None) => match solutions.iter().filter(|solution| solution.year == year).collect::<Vec<_>>() {
+      days if days.is_empty() => fail("Unknown year, come back later! o7"),
+      days => days.into_iter().map(|solution| (solution, solution.parts())).collect(),
+    },
+
+    (Some(year), Some(day)) => {
+      let Some(solution) = solutions.iter().find(|solution| solution.year == year && solution.day == day) else {
+        fail("Unknown day, come back later! o7");
+      };
+
+      let parts = match cli.part {
+        Some(part @ (1 | 2)) if solution.has_part(part) => vec![part],
+        Some(part @ (1 | 2)) => fail(format!("Day {day} part {part} is not solved yet, come back later! o7")),
+        _ => solution.parts(),
+      };
+
+      vec![(solution, parts)]
+    }
+  };
+
+  // Each year has its own answers file, only those of the years being run are
+  // needed.
+  settings.answers = check.then(|| {
+    let mut answers = Answers::default();
+
+    for year in selected.iter().map(|(solution, _)| solution.year).unique() {
+      let file = check::answers_file(year, settings.test);
+
+      match Answers::load(year, &file) {
+        Ok(year) => answers.extend(year),
+        Err(error) => fail_in(error, &file, &util::read_data(&file).unwrap_or_default()),
+      }
+    }
+
+    answers
+  });
+
+  if cli.list_options {
+    for (solution, _) in &selected {
+      let options = solution.describe();
+
+      if !options.is_empty() {
+        println!("{} day {}:", solution.year, solution.day);
+
+        for option in options {
+          println!("  {} = {}: {}", option.name, option.default, option.help);
+        }
+      }
+    }
+
+    process::exit(0);
+  }
+
+  check_overrides(&settings.overrides, selected.iter().map(|(solution, _)| *solution));
+
+  let selected = selected
+    .into_iter()
+    .map(|(solution, parts)| {
+      let options = configure(solution, settings.test && settings.input.is_none(), &settings.overrides).unwrap_or_else(|message| fail(message));
+
+      (solution, parts, options)
+    })
+    .collect::<Vec<_>>();
+
+  let mut records = Vec::new();
+
+  let mut report = |day: Vec<Record>| {
+    if settings.format == Format::Text {
+      for record in &day {
+        println!("{}", record.text(settings.timings));
+      }
+    }
+
+    records.extend(day);
+  };
+
+  // Parts that fail (broken input, panic, timeout...) are reported in their
+  // status row, the others still get to run.
+  match settings.jobs {
+    None => {
+      for (solution, parts, options) in &selected {
+        report(execute(solution, parts, options, &settings));
+      }
+    }
+
+    Some(jobs) => {
+      let pool = ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .unwrap_or_else(|error| fail(format!("Could not start the thread pool: {error}")));
+
+      let (sender, receiver) = mpsc::channel();
+
+      pool.in_place_scope(|scope| {
+        for (index, (solution, parts, options)) in selected.iter().enumerate() {
+          let (sender, settings) = (sender.clone(), &settings);
+
+          scope.spawn(move |_| {
+            let _ = sender.send((index, execute(solution, parts, options, settings)));
+          });
+        }
+
+        drop(sender);
+
+        // Days are reported in order, as soon as all the days before them are
+        // done, regardless of the order in which they complete.
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+
+        for (index, day) in receiver {
+          pending.insert(index, day);
+
+          while let Some(day) = pending.remove(&next) {
+            report(day);
+            next += 1;
+          }
+        }
+      });
+    }
+  }
+
+  match settings.format {
+    Format::Text => {
+      let benchmarks = records.iter().filter_map(|record| Some((record.year, record.day, record.part, record.bench?))).collect::<Vec<_>>();
+
+      if !benchmarks.is_empty() {
+        println!("\n{}", bench::table(&benchmarks));
+      }
+    }
+
+    format => println!("{}", output::render(format, &records)),
+  }
+
+  // Timings on foreign inputs are not comparable with the others.
+  if !cli.no_history && settings.input.is_none() && !records.is_empty() {
+    let file = history::history_file();
+
+    if let Err(error) = Run::new(&records, settings.test, cli.baseline.clone()).append(&file) {
+      eprintln!("Could not record the timings to {}: {error}", file.display());
+    }
+  }
+
+  let failures = records.iter().filter(|record| record.outcome != Outcome::Solved).count();
+  let (_, mismatches, _) = check::summary(&records);
+
+  if settings.answers.is_some() {
+    if settings.format == Format::Text {
+      let (pass, fail, missing) = check::summary(&records);
+
+      println!("\nCheck: {pass} passed, {fail} failed, {missing} missing");
+    }
+
+    if let Some(junit) = &cli.junit {
+      if let Err(error) = fs::write(junit, check::junit(&records)) {
+        fail(format!("Could not write {junit}: {error}"));
+      }
+    }
+  }
+
+  match failures > 0 || mismatches > 0 {
+    true => process::exit(1),
+    false => process::exit(0),
+  }
+}
+
+// Options set on the command line only apply to the days that know about them,
+// as several days may be run at once, but each has to be known by one.
+pub fn check_overrides<'a>(overrides: &[Setting], solutions: impl Iterator<Item = &'a Solution> + Clone) {
+  for setting in overrides {
+    if !solutions.clone().any(|solution| solution.describe().iter().any(|option| option.name == setting.name)) {
+      fail(format!("Unknown option `{}`, see --list-options for those of the selected days.", setting.name));
+    }
+  }
+}
+
+// Options come from their defaults, then from the defaults file of the day and
+// finally from the command line. Errors are rendered, ready to be shown.
+pub fn configure(solution: &Solution, test: bool, overrides: &[Setting]) -> Result<Configured, String> {
+  let file = options::defaults_file(solution.year, solution.day, test);
+  let render = |error: Error, content: &str| error.in_file(&util::source_name(&file)).render(content);
+
+  let (content, defaults) = options::load_defaults(&file).map_err(|error| render(error, ""))?;
+  let known = solution.describe();
+
+  let settings = defaults
+    .iter()
+    .chain(overrides.iter().filter(|setting| known.iter().any(|option| option.name == setting.name)))
+    .collect::<Vec<_>>();
+
+  solution.configure(&settings).map_err(|(index, expected)| match &settings[index].span {
+    Some(span) => render(Error::at_offset(&content, span.clone(), expected), &content),
+    None => format!("Invalid option `{}={}`, expected {expected}.", settings[index].name, settings[index].value),
+  })
+}
+
+pub fn execute(solution: &Solution, parts: &[usize], options: &Configured, settings: &Settings) -> Vec<Record> {
+  let files = parts
+    .iter()
+    .map(|&part| match &settings.input {
+      Some(input) => (part, input.clone()),
+      None => (part, util::input_file(solution.year, solution.day, part, settings.test)),
+    })
+    .collect::<Vec<_>>();
+
+  let mut records = match settings.limits.isolated() {
+    true => schedule(settings, &files, |(part, file)| run_isolated(solution, *part, file, settings)),
+
+    // Both parts usually share the same input file, in which case it is only
+    // read and parsed once (or reported as broken once), and the time it took
+    // is attributed to the first part. This also guarantees that stdin is only
+    // consumed once.
+    false => {
+      let inputs = files.iter().map(|(_, file)| file).unique().map(|file| (file, load_input(solution, file, settings))).collect::<Vec<_>>();
+
+      let parts = files
+        .iter()
+        .enumerate()
+        .map(|(index, (part, file))| {
+          let (_, (input, timings)) = inputs.iter().find(|(cached, _)| *cached == file).expect("every input is loaded");
+          let first = files.iter().position(|(_, other)| other == file) == Some(index);
+
+          (*part, file, input, timings.filter(|_| first))
+        })
+        .collect::<Vec<_>>();
+
+      schedule(settings, &parts, |(part, file, input, timings)| solve(solution, *part, options, file, input, *timings, settings))
+    }
+  };
+
+  if let Some(answers) = &settings.answers {
+    for record in &mut records {
+      record.check = Some(answers.check(record));
+    }
+  }
+
+  records
+}
+
+// Parts are only spread across the thread pool when running concurrently, so
+// that they otherwise run one after the other, in order.
+fn schedule<T, F>(settings: &Settings, items: &[T], f: F) -> Vec<Record>
+where
+  T: Sync,
+  F: Fn(&T) -> Record + Sync + Send,
+{
+  match settings.jobs {
+    Some(_) => items.par_iter().map(f).collect(),
+    None => items.iter().map(f).collect(),
+  }
+}
+
+// What to report for a part that could not be solved.
+type Failure = (Outcome, String);
+
+fn load_input(solution: &Solution, file: &Path, settings: &Settings) -> (Result<Parsed, Failure>, Option<(Duration, Duration)>) {
+  match settings.measure(|| isolate::catch(|| load(solution, file))) {
+    Ok(Ok((input, timings))) => (Ok(input), Some(timings)),
+
+    Ok(Err((error, input))) => {
+      let error = error.in_file(&util::source_name(file));
+
+      eprintln!("{}\n", error.render(input.text()));
+      (Err((Outcome::InvalidInput, error.to_string())), None)
+    }
+
+    Err(panic) => (Err((Outcome::Panicked, panic)), None),
+  }
+}
+
+fn solve(solution: &Solution, part: usize, options: &Configured, file: &Path, input: &Result<Parsed, Failure>, timings: Option<(Duration, Duration)>, settings: &Settings) -> Record {
+  let (year, day) = (solution.year, solution.day);
+  let name = util::source_name(file);
+  let test = settings.test && settings.input.is_none();
+
+  let input = match input {
+    Ok(input) => input,
+    Err((outcome, message)) => return Record::failed(year, day, part, *outcome, Some(message.clone()), name, test),
+  };
+
+  let solved = settings.measure(|| {
+    isolate::catch(|| {
+      let span = trace::span(format_args!("{year} day {day} part {part}"));
+      let before = Instant::now();
+      let (answer, memory) = memory::measure(|| solution.solve(part, input, options));
+      let solve = before.elapsed();
+
+      drop(span);
+
+      // The parsed input is reused across iterations, only solving is measured.
+      let bench = settings.bench.as_ref().map(|config| bench::measure(config, || solution.solve(part, input, options)));
+
+      (answer, solve, memory, bench)
+    })
+  });
+
+  match solved {
+    Ok((answer, solve, memory, bench)) => Record {
+      answer: Some(answer),
+      read: timings.map(|(read, _)| read),
+      parse: timings.map(|(_, parse)| parse),
+      solve: Some(solve),
+      memory,
+      bench,
+      ..Record::failed(year, day, part, Outcome::Solved, None, name, test)
+    },
+
+    // The input was read and parsed all the same.
+    Err(panic) => Record {
+      read: timings.map(|(read, _)| read),
+      parse: timings.map(|(_, parse)| parse),
+      ..Record::failed(year, day, part, Outcome::Panicked, Some(panic), name, test)
+    },
+  }
+}
+
+// Runs a single part in a child process, which is the binary itself asked to
+// print its results as JSON, so that limits can be enforced on it.
+fn run_isolated(solution: &Solution, part: usize, file: &Path, settings: &Settings) -> Record {
+  let (year, day) = (solution.year, solution.day);
+  let mut arguments = vec!["--format".to_string(), "json".to_string(), "--no-history".to_string()];
+
+  arguments.extend(["--data-dir".to_string(), util::data_dir().display().to_string()]);
+
+  if settings.test {
+    arguments.push("--test".to_string());
+  }
+
+  if let Some(input) = &settings.input {
+    arguments.extend(["--input".to_string(), input.display().to_string()]);
+  }
+
+  if let Some(config) = &settings.bench {
+    arguments.extend(["--bench".to_string(), "--warmup".to_string(), config.warmup.to_string()]);
+
+    if let Some(iterations) = config.iterations {
+      arguments.extend(["--iterations".to_string(), iterations.to_string()]);
+    }
+
+    if let Some(budget) = config.budget {
+      arguments.extend(["--budget".to_string(), budget.as_secs_f64().to_string()]);
+    }
+  }
+
+  // Parts run in their own process trace to its stderr, which is passed on once
+  // it is done.
+  arguments.extend(iter::repeat_n("--verbose".to_string(), trace::level().into()));
+
+  for setting in &settings.overrides {
+    arguments.extend(["--set".to_string(), format!("{}={}", setting.name, setting.value)]);
+  }
+
+  arguments.extend([year.to_string(), day.to_string(), part.to_string()]);
+
+  let failed = |outcome, message| Record::failed(year, day, part, outcome, Some(message), util::source_name(file), settings.test && settings.input.is_none());
+
+  match isolate::spawn(&arguments, settings.stdin.as_deref(), &settings.limits) {
+    Ok(records) => match <[Record; 1]>::try_from(records) {
+      Ok([record]) => record,
+      Err(_) => failed(Outcome::Crashed, "did not report a result".to_string()),
+    },
+
+    Err((outcome, message)) => failed(outcome, message),
+  }
+}
+
+// Errors come with the lines that were read, if any, so that they can be
+// quoted without reading the input again, which is not possible with stdin.
+pub type LoadError = (Error, Input);
+
+pub fn load(solution: &Solution, file: &Path) -> Result<(Parsed, (Duration, Duration)), LoadError> {
+  let before = Instant::now();
+  let input = Input::read(file).map_err(|error| (error, Input::new("")))?;
+  let read = before.elapsed();
+
+  let before = Instant::now();
+  let parsed = solution.parse(&input).map_err(|error| (error, input))?;
+  let parse = before.elapsed();
+
+  Ok((parsed, (read, parse)))
+}
@@ -0,0 +1,153 @@
+use std::{
+  fs::{self, OpenOptions},
+  io::Write,
+  path::{Path, PathBuf},
+  process,
+};
+
+use crate::{
+  check,
+  cli::{fail, Cli},
+  day::Solution,
+  error::{Error, Result},
+  util,
+};
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+// The sources are generated next to the other days, in the source tree of the
+// runner rather than wherever it is run from.
+pub fn year_sources(year: usize) -> PathBuf {
+  Path::new(env!("CARGO_MANIFEST_DIR")).join("src/days").join(year.to_string())
+}
+
+// Sources are shown relative to the crate, as they appear in the tree.
+pub fn display(file: &Path) -> String {
+  file.strip_prefix(env!("CARGO_MANIFEST_DIR")).unwrap_or(file).display().to_string()
+}
+
+pub fn day_source(year: usize, day: usize) -> PathBuf {
+  year_sources(year).join(format!("day{day:0>2}.rs"))
+}
+
+pub fn render(year: usize, day: usize) -> String {
+  TEMPLATE
+    .replace("{{DD}}", &format!("{day:0>2}"))
+    .replace("{{YEAR}}", &year.to_string())
+    .replace("{{DAY}}", &day.to_string())
+}
+
+// Files making up a new day, along with their content: its module, built from
+// the template, and empty example and puzzle inputs. The first day of a year
+// also gets the module of the year.
+pub fn files(year: usize, day: usize) -> Vec<(PathBuf, String)> {
+  let mut files = vec![];
+
+  if !year_sources(year).join("mod.rs").exists() {
+    files.push((year_sources(year).join("mod.rs"), format!("automod::dir!(pub \"src/days/{year}\");\n")));
+  }
+
+  files.push((day_source(year, day), render(year, day)));
+  files.push((util::input_file(year, day, 1, true), String::new()));
+  files.push((util::input_file(year, day, 1, false), String::new()));
+  files
+}
+
+// Files are only ever created, an existing one is never written to.
+pub fn create(file: &Path, content: &str) -> Result<()> {
+  let io = |source| Error::Io {
+    file: util::source_name(file),
+    source,
+  };
+
+  if let Some(parent) = file.parent() {
+    fs::create_dir_all(parent).map_err(io)?;
+  }
+
+  OpenOptions::new()
+    .write(true)
+    .create_new(true)
+    .open(file)
+    .and_then(|mut handle| handle.write_all(content.as_bytes()))
+    .map_err(io)
+}
+
+// Years are declared by hand in `src/days/mod.rs`, since automod only handles
+// flat directories.
+pub fn register_year(year: usize) -> Result<bool> {
+  let file = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/days/mod.rs");
+  let io = |source| Error::Io {
+    file: util::source_name(&file),
+    source,
+  };
+
+  let content = fs::read_to_string(&file).map_err(io)?;
+  let module = format!("pub mod y{year};");
+
+  if content.lines().any(|line| line.trim() == module) {
+    return Ok(false);
+  }
+
+  let mut handle = OpenOptions::new().append(true).open(&file).map_err(io)?;
+
+  write!(handle, "\n#[path = \"{year}/mod.rs\"]\n{module}\n").map_err(io)?;
+
+  Ok(true)
+}
+
+// Starts a new day, refusing to overwrite any of its files, and lists what is
+// left to fill in.
+pub fn run(cli: &Cli, solutions: &[Solution]) -> ! {
+  let day = cli.day.unwrap_or_default();
+  let year = cli.year(solutions);
+
+  if !(1..=25).contains(&day) {
+    fail("Days go from 1 to 25.");
+  }
+
+  let files = files(year, day);
+  let existing = files.iter().filter(|(file, _)| file.exists()).collect::<Vec<_>>();
+
+  if !existing.is_empty() {
+    for (file, _) in existing {
+      eprintln!("Refusing to overwrite {}", display(file));
+    }
+
+    process::exit(1);
+  }
+
+  for (file, content) in &files {
+    if let Err(error) = create(file, content) {
+      fail(error);
+    }
+
+    println!("Created {}", display(file));
+  }
+
+  match register_year(year) {
+    Ok(true) => println!("Registered the y{year} module in src/days/mod.rs"),
+    Ok(false) => {}
+    Err(error) => fail(error),
+  }
+
+  println!("\nStill to fill in:");
+  println!("  {}: the example input", util::input_file(year, day, 1, true).display());
+  println!("  {}: the puzzle input", util::input_file(year, day, 1, false).display());
+  println!("  {}: the expected answers of the examples, `(0, 0)` in `crate::tests!`", display(&day_source(year, day)));
+  println!("  {}: `part1` and `part2` under `[day{day:0>2}]`, to use --check", check::answers_file(year, true).display());
+
+  process::exit(0);
+}
+
+#[cfg(test)]
+mod tests {
+  #[test]
+  fn render() {
+    let source = super::render(2023, 5);
+
+    assert!(source.contains("crate::tests!(Day05, 2023, 5, (0, 0));"));
+    assert!(source.contains("impl Day for Day05 {"));
+    assert!(source.contains("#[aoc::solution(day = 5, part = 2)]"));
+    assert!(!source.contains("{{"));
+  }
+}
@@ -0,0 +1,115 @@
+use std::fmt;
+
+use crate::{
+  check::{self, Answers},
+  day::Day,
+  error::Result,
+  input::Input,
+  options::{self, Options},
+  util,
+};
+
+// Where the input of a test case comes from.
+#[derive(Debug, Clone, Copy)]
+pub enum Source {
+  // The example of the part, as found by `util::input_file`.
+  Example,
+  Inline(&'static str),
+  // A file in the data directory of the year.
+  File(&'static str),
+}
+
+pub fn example() -> Source {
+  Source::Example
+}
+
+pub fn inline(text: &'static str) -> Source {
+  Source::Inline(text)
+}
+
+pub fn file(name: &'static str) -> Source {
+  Source::File(name)
+}
+
+impl Source {
+  pub fn input(&self, year: usize, day: usize, part: usize) -> Result<Input> {
+    match self {
+      Source::Example => Input::read(util::input_file(year, day, part, true)),
+      Source::Inline(text) => Ok(Input::new(dedent(text))),
+      Source::File(name) => Input::read(util::year_dir(year).join(name)),
+    }
+  }
+}
+
+impl fmt::Display for Source {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Source::Example => write!(f, "example"),
+      Source::Inline(text) => write!(f, "inline {:?}", text.trim()),
+      Source::File(name) => write!(f, "file {name}"),
+    }
+  }
+}
+
+// Inline inputs can be indented along with the code around them, and start on
+// the line after the opening quote.
+fn dedent(text: &str) -> String {
+  let text = text.strip_prefix('\n').unwrap_or(text);
+  let lines = text.lines().collect::<Vec<_>>();
+  let lines = match lines.last() {
+    Some(last) if last.trim().is_empty() => &lines[..lines.len() - 1],
+    _ => &lines[..],
+  };
+
+  let indent = lines.iter().filter(|line| !line.trim().is_empty()).map(|line| line.len() - line.trim_start().len()).min().unwrap_or(0);
+
+  lines.iter().map(|line| line.get(indent..).unwrap_or_default()).collect::<Vec<_>>().join("\n")
+}
+
+// Solves a part on the real input, with the defaults of the options for it,
+// and compares the answer with the known one. Inputs and answers are usually
+// not part of the repository, so the test passes when either is missing.
+pub fn real<D>(year: usize, day: usize, part: usize) -> Result<()>
+where
+  D: Day,
+{
+  let file = util::input_file(year, day, part, false);
+  let answers = check::answers_file(year, false);
+
+  if !util::data_exists(&file) || !util::data_exists(&answers) {
+    eprintln!("skipped: {} or {} is missing", file.display(), answers.display());
+    return Ok(());
+  }
+
+  let Some(expected) = Answers::load(year, &answers)?.get(year, day, part).map(String::from) else {
+    eprintln!("skipped: no known answer for {year} day {day} part {part}");
+    return Ok(());
+  };
+
+  let input = D::parse(&Input::read(&file)?)?;
+  let mut opts = D::Options::default();
+
+  for setting in options::load_defaults(&options::defaults_file(year, day, false))?.1 {
+    if let Err(expected) = opts.set(&setting.name, &setting.value) {
+      panic!("invalid default for `{}`, expected {expected}", setting.name);
+    }
+  }
+
+  let answer = match part {
+    1 => D::part1(&input, &opts),
+    _ => D::part2(&input, &opts),
+  };
+
+  assert_eq!(answer.to_string(), expected);
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  #[test]
+  fn dedent() {
+    assert_eq!(super::dedent("\n    1abc2\n      pqr3\n    \n"), "1abc2\n  pqr3");
+    assert_eq!(super::dedent("eightwo"), "eightwo");
+  }
+}
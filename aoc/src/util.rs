@@ -0,0 +1,377 @@
+use std::{
+  any, env,
+  fmt::{Debug, Display},
+  fs, io, iter,
+  path::{Path, PathBuf},
+  str::FromStr,
+  sync::OnceLock,
+};
+
+use crate::error::{Error, Result};
+
+pub mod trace;
+
+// Tests of a day, on any number of cases per part, each with its input (the
+// example of the part, an inline string or a file from the data directory of
+// the year), its options by name and its expected answer:
+//
+//   crate::tests!(Day21, 2023, 21, {
+//     part1: [({ steps: 6 } example() => 16), (inline("S") => 1)],
+//     part2: [],
+//   });
+//
+// Tests on the real input, checked against the known answers, are ignored by
+// default and pass when either is missing.
+#[macro_export]
+macro_rules! tests {
+  (@case $solution:ident, $year:literal, $day:literal, $part:literal, $method:ident, ({ $($name:ident: $value:expr),* } $source:expr => $expected:expr)) => {{
+    let source = $source;
+    let input = super::$solution::parse(&source.input($year, $day, $part)?)?;
+    #[allow(unused_mut)]
+    let mut opts = <super::$solution as Day>::Options::default();
+
+    $(opts.$name = $value;)*
+
+    assert_eq!(super::$solution::$method(&input, &opts), $expected, "on {source}");
+  }};
+
+  (@case $solution:ident, $year:literal, $day:literal, $part:literal, $method:ident, ($source:expr => $expected:expr)) => {
+    $crate::tests!(@case $solution, $year, $day, $part, $method, ({} $source => $expected))
+  };
+
+  ($solution:ident, $year:literal, $day:literal, ($result1:literal, $result2:literal)) => {
+    $crate::tests!($solution, $year, $day, { part1: [(example() => $result1)], part2: [(example() => $result2)] });
+  };
+
+  ($solution:ident, $year:literal, $day:literal, ({ $($name1:ident: $value1:expr),* } => $result1:literal, { $($name2:ident: $value2:expr),* } => $result2:literal)) => {
+    $crate::tests!($solution, $year, $day, {
+      part1: [({ $($name1: $value1),* } example() => $result1)],
+      part2: [({ $($name2: $value2),* } example() => $result2)],
+    });
+  };
+
+  ($solution:ident, $year:literal, $day:literal, { part1: [$($case1:tt),* $(,)?], part2: [$($case2:tt),* $(,)?] $(,)? }) => {
+    #[cfg(test)]
+    mod tests {
+      #[allow(unused_imports)]
+      use $crate::{day::Day, testing::*};
+
+      #[test]
+      fn part1() -> $crate::error::Result<()> {
+        $($crate::tests!(@case $solution, $year, $day, 1, part1, $case1);)*
+
+        Ok(())
+      }
+
+      #[test]
+      fn part2() -> $crate::error::Result<()> {
+        $($crate::tests!(@case $solution, $year, $day, 2, part2, $case2);)*
+
+        Ok(())
+      }
+
+      #[test]
+      #[ignore = "runs on the real input"]
+      fn real_part1() -> $crate::error::Result<()> {
+        $crate::testing::real::<super::$solution>($year, $day, 1)
+      }
+
+      #[test]
+      #[ignore = "runs on the real input"]
+      fn real_part2() -> $crate::error::Result<()> {
+        $crate::testing::real::<super::$solution>($year, $day, 2)
+      }
+    }
+  };
+}
+
+// Path that stands for the standard input wherever an input file is expected.
+pub const STDIN: &str = "-";
+
+static DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+pub fn set_data_dir<P>(dir: P)
+where
+  P: Into<PathBuf>,
+{
+  let _ = DATA_DIR.set(dir.into());
+}
+
+// Puzzle inputs are looked up, in order, in the directory given on the command
+// line, in `$AOC_DATA_DIR`, in `data/` under the current directory and finally
+// in `data/` next to the crate manifest, so that both the binary and the tests
+// can be run from anywhere.
+pub fn data_dir() -> PathBuf {
+  if let Some(dir) = DATA_DIR.get() {
+    return dir.clone();
+  }
+
+  if let Some(dir) = env::var_os("AOC_DATA_DIR").filter(|dir| !dir.is_empty()) {
+    return PathBuf::from(dir);
+  }
+
+  match Path::new("data").is_dir() {
+    true => PathBuf::from("data"),
+    false => Path::new(env!("CARGO_MANIFEST_DIR")).join("data"),
+  }
+}
+
+// Each event has its own directory under the data directory (e.g. `data/2023`).
+pub fn year_dir(year: usize) -> PathBuf {
+  data_dir().join(year.to_string())
+}
+
+pub fn input_file(year: usize, day: usize, part: usize, test: bool) -> PathBuf {
+  let dir = year_dir(year);
+
+  match test {
+    true => match dir.join(format!("{day:0>2}_{part}_test.txt")) {
+      path if data_exists(&path) => path,
+      _ => dir.join(format!("{day:0>2}_test.txt")),
+    },
+
+    false => dir.join(format!("{day:0>2}.txt")),
+  }
+}
+
+// Files of the data directory embedded in the binary with the `embed-inputs`
+// feature, by their path relative to it, for when it is run away from them.
+#[cfg(feature = "embed-inputs")]
+static EMBEDDED: &[(&str, &str)] = aoc::embed_inputs!();
+#[cfg(not(feature = "embed-inputs"))]
+static EMBEDDED: &[(&str, &str)] = &[];
+
+fn embedded(path: &Path) -> Option<&'static str> {
+  let relative = path.strip_prefix(data_dir()).ok()?;
+  let name = relative.iter().map(|component| component.to_string_lossy()).collect::<Vec<_>>().join("/");
+
+  EMBEDDED.iter().find(|(embedded, _)| *embedded == name).map(|(_, content)| *content)
+}
+
+// Reads a file of the data directory, the one on the filesystem taking
+// precedence over the embedded copy.
+pub fn read_data(path: &Path) -> io::Result<String> {
+  match fs::read_to_string(path) {
+    Err(error) if error.kind() == io::ErrorKind::NotFound => embedded(path).map(String::from).ok_or(error),
+    content => content,
+  }
+}
+
+pub fn data_exists(path: &Path) -> bool {
+  path.exists() || embedded(path).is_some()
+}
+
+pub fn source_name(input: &Path) -> String {
+  match input == Path::new(STDIN) {
+    true => "<stdin>".to_string(),
+    false => input.display().to_string(),
+  }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+  pub number: usize,
+  pub text: &'a str,
+}
+
+// All the tokens given to those helpers are expected to be slices of the line
+// itself, which is how the column of the error is recovered.
+impl<'a> Line<'a> {
+  fn column(&self, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+
+    match self.text.get(..offset) {
+      Some(before) => before.chars().count() + 1,
+      None => 1,
+    }
+  }
+
+  pub fn error<E>(&self, token: &str, expected: E) -> Error
+  where
+    E: Display,
+  {
+    Error::parse(self.number, self.column(token), token.chars().count(), expected)
+  }
+
+  pub fn error_at<E>(&self, index: usize, expected: E) -> Error
+  where
+    E: Display,
+  {
+    Error::parse(self.number, index + 1, 1, expected)
+  }
+
+  pub fn split_once(&self, within: &'a str, delimiter: &str) -> Result<(&'a str, &'a str)> {
+    within.split_once(delimiter).ok_or_else(|| self.error(within, format!("`{delimiter}`")))
+  }
+
+  pub fn strip_prefix(&self, within: &'a str, prefix: &str) -> Result<&'a str> {
+    within.strip_prefix(prefix).ok_or_else(|| self.error(within, format!("`{prefix}`")))
+  }
+
+  pub fn parse<T>(&self, token: &'a str) -> Result<T>
+  where
+    T: FromStr,
+  {
+    token.parse::<T>().map_err(|_| self.error(token, format!("`{}`", any::type_name::<T>())))
+  }
+
+  pub fn convert<T>(&self, token: &'a str) -> Result<T>
+  where
+    T: TryFrom<&'a str, Error = &'static str>,
+  {
+    T::try_from(token).map_err(|expected| self.error(token, expected))
+  }
+
+  pub fn cells<T, F>(&self, within: &'a str, mut cell: F) -> Result<Vec<T>>
+  where
+    F: FnMut(char) -> Result<T, &'static str>,
+  {
+    let start = self.column(within) - 1;
+
+    within
+      .chars()
+      .enumerate()
+      .map(|(index, symbol)| cell(symbol).map_err(|expected| self.error_at(start + index, expected)))
+      .collect()
+  }
+
+  // Every integer of the line, ignoring whatever is around them, such as the
+  // `x=` and commas of `x=-3, y=12`.
+  pub fn ints<T>(&self) -> impl Iterator<Item = Result<T>> + '_
+  where
+    T: FromStr,
+  {
+    let text = self.text;
+    let bytes = text.as_bytes();
+    let mut index = 0;
+
+    iter::from_fn(move || {
+      let start = (index..bytes.len()).find(|&at| bytes[at].is_ascii_digit())?;
+      let end = (start..bytes.len()).find(|&at| !bytes[at].is_ascii_digit()).unwrap_or(bytes.len());
+      let start = match start > 0 && bytes[start - 1] == b'-' {
+        true => start - 1,
+        false => start,
+      };
+
+      index = end;
+
+      Some(self.parse::<T>(&text[start..end]))
+    })
+  }
+}
+
+// Types that can be read from a token of a line, usually derived with
+// `#[derive(aoc::FromInput)]`, so that errors point at the part of the line
+// that could not be read.
+pub trait FromInput<'a>: Sized {
+  fn from_input(line: &Line<'a>, token: &'a str) -> Result<Self>;
+
+  fn from_lines<I>(lines: I) -> Result<Vec<Self>>
+  where
+    I: IntoIterator<Item = Line<'a>>,
+  {
+    lines.into_iter().map(|line| Self::from_input(&line, line.text)).collect()
+  }
+}
+
+macro_rules! from_str_input {
+  ($($type:ty),*) => {
+    $(
+      impl<'a> FromInput<'a> for $type {
+        fn from_input(line: &Line<'a>, token: &'a str) -> Result<Self> {
+          line.parse(token)
+        }
+      }
+    )*
+  };
+}
+
+from_str_input!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, bool, char, String);
+
+impl<'a> FromInput<'a> for &'a str {
+  fn from_input(_line: &Line<'a>, token: &'a str) -> Result<Self> {
+    Ok(token)
+  }
+}
+
+pub fn transpose<T>(matrix: Vec<Vec<T>>) -> Vec<Vec<T>>
+where
+  T: Clone,
+{
+  (0..matrix[0].len()).map(|index| matrix.iter().map(|row| row[index].clone()).collect::<Vec<_>>()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::Path;
+
+  use aoc::FromInput;
+
+  use super::FromInput;
+  use crate::{error::Error, input::Input};
+
+  #[derive(Debug, PartialEq, FromInput)]
+  enum Kind {
+    #[aoc(fmt = "plain")]
+    Plain,
+    #[aoc(fmt = "x{0}")]
+    Scaled(u8),
+  }
+
+  #[derive(Debug, PartialEq, FromInput)]
+  #[aoc(fmt = "{name} [{kind}]: {values}")]
+  struct Entry<'a> {
+    name: &'a str,
+    kind: Kind,
+    #[aoc(sep = " | ", sep = " ")]
+    values: Vec<Vec<i64>>,
+  }
+
+  #[test]
+  fn input_file_naming() {
+    assert!(super::input_file(2023, 8, 1, true).ends_with("2023/08_1_test.txt"));
+    assert!(super::input_file(2023, 2, 1, true).ends_with("2023/02_test.txt"));
+    assert!(super::input_file(2023, 2, 1, false).ends_with("2023/02.txt"));
+    assert_eq!(super::source_name(Path::new("-")), "<stdin>");
+  }
+
+  #[test]
+  fn from_input() {
+    let input = Input::new("a [plain]: 1 2 | 3\nb [x4]: ");
+
+    assert_eq!(
+      Entry::from_lines(input.lines()).unwrap(),
+      [
+        Entry {
+          name: "a",
+          kind: Kind::Plain,
+          values: vec![vec![1, 2], vec![3]],
+        },
+        Entry {
+          name: "b",
+          kind: Kind::Scaled(4),
+          values: vec![vec![]],
+        }
+      ]
+    );
+
+    let errors = [("a [plain] 1", (1, 4)), ("a [xy]: 1", (1, 5)), ("a [round]: 1", (1, 4)), ("a [x2]: 1 two", (1, 11))];
+
+    for (text, position) in errors {
+      let input = Input::new(text);
+      let Err(Error::Parse(error)) = Entry::from_lines(input.lines()) else { panic!("{text}") };
+
+      assert_eq!((error.line, error.column), position, "{text}: {}", error.expected);
+    }
+  }
+
+  #[test]
+  fn ints() {
+    let input = Input::new("x=-3, y=12\nnone");
+    let lines = input.lines().collect::<Vec<_>>();
+
+    assert_eq!(lines[0].ints::<i64>().collect::<Result<Vec<_>, _>>().unwrap(), [-3, 12]);
+    assert_eq!(lines[1].ints::<i64>().count(), 0);
+    assert!(lines[0].ints::<u8>().next().unwrap().is_err());
+  }
+}
@@ -0,0 +1,203 @@
+use std::{
+  fmt,
+  fs::File,
+  io::{self, LineWriter, Write},
+  path::Path,
+  sync::{
+    atomic::{AtomicU8, Ordering::Relaxed},
+    Mutex, OnceLock, PoisonError,
+  },
+  time::{Duration, Instant},
+};
+
+use serde::Serialize;
+
+// Spans and progress are traced with `-v`, counters and more frequent progress
+// on top of them with `-vv`.
+pub const INFO: u8 = 1;
+pub const DEBUG: u8 = 2;
+
+// Nothing is traced until `init`, and checking whether something should be is
+// a single relaxed load, so that instrumented loops cost next to nothing when
+// tracing is off.
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+static SINK: OnceLock<Sink> = OnceLock::new();
+
+struct Sink {
+  start: Instant,
+  // Events go to stderr as text, unless they are written to a trace file, one
+  // JSON object per line.
+  file: Option<Mutex<LineWriter<File>>>,
+}
+
+pub fn init(level: u8, file: Option<&Path>) -> io::Result<()> {
+  let file = file.map(File::create).transpose()?.map(|file| Mutex::new(LineWriter::new(file)));
+
+  let _ = SINK.set(Sink { start: Instant::now(), file });
+
+  LEVEL.store(level, Relaxed);
+
+  Ok(())
+}
+
+#[inline]
+pub fn enabled(level: u8) -> bool {
+  LEVEL.load(Relaxed) >= level
+}
+
+pub fn level() -> u8 {
+  LEVEL.load(Relaxed)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+enum Event<'a> {
+  Enter { name: &'a str },
+  Exit { name: &'a str, seconds: f64 },
+  Counter { name: &'a str, value: u64 },
+  Progress { name: &'a str, done: u64, total: Option<u64>, rate: f64 },
+}
+
+impl fmt::Display for Event<'_> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Event::Enter { name } => write!(f, "> {name}"),
+      Event::Exit { name, seconds } => write!(f, "< {name} ({:.2?})", Duration::from_secs_f64(*seconds)),
+      Event::Counter { name, value } => write!(f, "{name} = {value}"),
+      Event::Progress { name, done, total: Some(total), rate } => {
+        write!(f, "{name}: {done}/{total} ({:.1}%, {rate:.0}/s)", *done as f64 * 100.0 / (*total).max(1) as f64)
+      }
+      Event::Progress { name, done, total: None, rate } => write!(f, "{name}: {done} ({rate:.0}/s)"),
+    }
+  }
+}
+
+#[derive(Serialize)]
+struct Line<'a> {
+  elapsed: f64,
+  #[serde(flatten)]
+  event: Event<'a>,
+}
+
+fn emit(event: Event) {
+  let Some(sink) = SINK.get() else { return };
+  let elapsed = sink.start.elapsed().as_secs_f64();
+
+  match &sink.file {
+    Some(file) => {
+      let mut file = file.lock().unwrap_or_else(PoisonError::into_inner);
+
+      // Tracing never gets in the way of solving, a trace file that cannot be
+      // written to is left incomplete.
+      let _ = serde_json::to_writer(&mut *file, &Line { elapsed, event }).map(|_| writeln!(file));
+    }
+
+    None => eprintln!("[{elapsed:>9.3}s] {event}"),
+  }
+}
+
+// Traces when it is entered, and when it is dropped along with how long it
+// lasted. Names are only formatted when traced, so that they can be given as
+// `format_args!`.
+pub struct Span {
+  entered: Option<(String, Instant)>,
+}
+
+pub fn span(name: impl fmt::Display) -> Span {
+  if !enabled(INFO) {
+    return Span { entered: None };
+  }
+
+  let name = name.to_string();
+
+  emit(Event::Enter { name: &name });
+
+  Span {
+    entered: Some((name, Instant::now())),
+  }
+}
+
+impl Drop for Span {
+  fn drop(&mut self) {
+    if let Some((name, start)) = &self.entered {
+      emit(Event::Exit {
+        name,
+        seconds: start.elapsed().as_secs_f64(),
+      });
+    }
+  }
+}
+
+pub fn counter(name: impl fmt::Display, value: u64) {
+  if enabled(DEBUG) {
+    emit(Event::Counter { name: &name.to_string(), value });
+  }
+}
+
+// Reports how far a loop went, at most every second, or every tenth of a
+// second with `-vv`, however often it is ticked.
+pub struct Progress {
+  name: &'static str,
+  total: Option<u64>,
+  every: Option<Duration>,
+  start: Instant,
+  last: Instant,
+}
+
+pub fn progress(name: &'static str, total: Option<u64>) -> Progress {
+  let every = match LEVEL.load(Relaxed) {
+    0 => None,
+    INFO => Some(Duration::from_secs(1)),
+    _ => Some(Duration::from_millis(100)),
+  };
+
+  let now = Instant::now();
+
+  Progress {
+    name,
+    total,
+    every,
+    start: now,
+    last: now,
+  }
+}
+
+impl Progress {
+  #[inline]
+  pub fn tick(&mut self, done: u64) {
+    let Some(every) = self.every else { return };
+    let now = Instant::now();
+
+    if now.duration_since(self.last) < every {
+      return;
+    }
+
+    self.last = now;
+
+    emit(Event::Progress {
+      name: self.name,
+      done,
+      total: self.total,
+      rate: done as f64 / now.duration_since(self.start).as_secs_f64(),
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Event;
+
+  #[test]
+  fn events() {
+    let progress = Event::Progress {
+      name: "cycles",
+      done: 250,
+      total: Some(1000),
+      rate: 125.0,
+    };
+
+    assert_eq!(progress.to_string(), "cycles: 250/1000 (25.0%, 125/s)");
+    assert_eq!(Event::Counter { name: "queue", value: 12 }.to_string(), "queue = 12");
+    assert_eq!(serde_json::to_string(&Event::Enter { name: "day14" }).unwrap(), r#"{"event":"enter","name":"day14"}"#);
+  }
+}
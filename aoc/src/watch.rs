@@ -0,0 +1,258 @@
+use std::{
+  collections::{HashMap, HashSet},
+  env, io,
+  path::{Path, PathBuf},
+  process::Command,
+  time::Duration,
+};
+
+use itertools::Itertools;
+
+use crate::{
+  cli::{fail, Cli},
+  isolate::{self, Limits},
+  options,
+  output::Record,
+  scaffold, util,
+};
+
+// Files a day depends on: its module, the shared helpers, all the inputs it
+// could be run against and the defaults of its options, whether they exist yet
+// or not.
+pub fn files(year: usize, day: usize) -> Vec<PathBuf> {
+  let data = util::year_dir(year);
+
+  vec![
+    scaffold::day_source(year, day),
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src/util.rs"),
+    data.join(format!("{day:0>2}_test.txt")),
+    data.join(format!("{day:0>2}_1_test.txt")),
+    data.join(format!("{day:0>2}_2_test.txt")),
+    data.join(format!("{day:0>2}.txt")),
+    options::defaults_file(year, day, true),
+    options::defaults_file(year, day, false),
+  ]
+}
+
+// Watches the directories holding the files rather than the files themselves,
+// since editors usually save by replacing the file, and so that inputs that do
+// not exist yet are picked up once created.
+#[cfg(target_os = "linux")]
+pub struct Watcher {
+  fd: std::os::fd::OwnedFd,
+  dirs: HashMap<i32, PathBuf>,
+  files: HashSet<PathBuf>,
+}
+
+#[cfg(target_os = "linux")]
+impl Watcher {
+  pub fn new(files: &[PathBuf]) -> io::Result<Watcher> {
+    use std::os::{fd::FromRawFd, unix::ffi::OsStrExt};
+
+    // SAFETY: inotify_init1 has no preconditions, its result is checked before
+    // being owned.
+    let fd = match unsafe { libc::inotify_init1(libc::IN_CLOEXEC) } {
+      -1 => return Err(io::Error::last_os_error()),
+      fd => unsafe { std::os::fd::OwnedFd::from_raw_fd(fd) },
+    };
+
+    let mut watcher = Watcher {
+      fd,
+      dirs: HashMap::new(),
+      files: files.iter().cloned().collect(),
+    };
+
+    for dir in files.iter().filter_map(|file| file.parent()).collect::<HashSet<_>>() {
+      let path = std::ffi::CString::new(dir.as_os_str().as_bytes()).map_err(io::Error::other)?;
+      let mask = libc::IN_CLOSE_WRITE | libc::IN_MOVED_TO | libc::IN_CREATE | libc::IN_DELETE;
+
+      // SAFETY: the path is a valid C string that outlives the call.
+      match unsafe { libc::inotify_add_watch(std::os::fd::AsRawFd::as_raw_fd(&watcher.fd), path.as_ptr(), mask) } {
+        // Directories that do not exist (yet) are not watched.
+        -1 if io::Error::last_os_error().kind() == io::ErrorKind::NotFound => {}
+        -1 => return Err(io::Error::last_os_error()),
+        wd => {
+          watcher.dirs.insert(wd, dir.to_path_buf());
+        }
+      }
+    }
+
+    Ok(watcher)
+  }
+
+  // Blocks until one of the files changes, events about the other files of
+  // their directories included, and returns all those that changed until
+  // things settled down, as saving usually comes in bursts of events.
+  pub fn wait(&mut self) -> io::Result<Vec<PathBuf>> {
+    let mut changed = vec![];
+
+    while changed.is_empty() {
+      changed = self.read(None)?.unwrap_or_default();
+    }
+
+    while let Some(more) = self.read(Some(Duration::from_millis(200)))? {
+      changed.extend(more);
+    }
+
+    changed.sort();
+    changed.dedup();
+
+    Ok(changed)
+  }
+
+  // Files among those watched that changed, none when nothing happened before
+  // the timeout.
+  fn read(&mut self, timeout: Option<Duration>) -> io::Result<Option<Vec<PathBuf>>> {
+    use std::os::{fd::AsRawFd, unix::ffi::OsStrExt};
+
+    let mut poll = libc::pollfd {
+      fd: self.fd.as_raw_fd(),
+      events: libc::POLLIN,
+      revents: 0,
+    };
+
+    let timeout = timeout.map(|timeout| timeout.as_millis() as libc::c_int).unwrap_or(-1);
+
+    // SAFETY: a single valid pollfd is passed.
+    match unsafe { libc::poll(&mut poll, 1, timeout) } {
+      -1 if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted => return Ok(Some(vec![])),
+      -1 => return Err(io::Error::last_os_error()),
+      0 => return Ok(None),
+      _ => {}
+    }
+
+    let mut buffer = [0u8; 4096];
+
+    // SAFETY: the buffer is valid for writes of its whole length.
+    let length = match unsafe { libc::read(self.fd.as_raw_fd(), buffer.as_mut_ptr().cast(), buffer.len()) } {
+      -1 => return Err(io::Error::last_os_error()),
+      length => length as usize,
+    };
+
+    let mut changed = vec![];
+    let mut offset = 0;
+
+    // Events are a header followed by the NUL-padded name of the file.
+    while offset + size_of::<libc::inotify_event>() <= length {
+      // SAFETY: the kernel only writes whole events, the header is read
+      // unaligned from within the bytes it wrote.
+      let event = unsafe { std::ptr::read_unaligned(buffer[offset..].as_ptr().cast::<libc::inotify_event>()) };
+      let start = offset + size_of::<libc::inotify_event>();
+      let name = &buffer[start..start + event.len as usize];
+      let name = std::ffi::OsStr::from_bytes(name.split(|byte| *byte == 0).next().unwrap_or_default());
+
+      if let Some(dir) = self.dirs.get(&event.wd) {
+        let file = dir.join(name);
+
+        if self.files.contains(&file) {
+          changed.push(file);
+        }
+      }
+
+      offset = start + event.len as usize;
+    }
+
+    Ok(Some(changed))
+  }
+}
+
+// Rebuilds the binary with the same profile as the one running, so that the
+// next run picks up the changes to the sources.
+pub fn rebuild() -> bool {
+  let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+
+  command.args(["build", "--quiet", "--bin", env!("CARGO_PKG_NAME")]).current_dir(env!("CARGO_MANIFEST_DIR"));
+
+  if !cfg!(debug_assertions) {
+    command.arg("--release");
+  }
+
+  command.status().is_ok_and(|status| status.success())
+}
+
+// Answers of the previous run of each part, on either kind of input, to show
+// what changed from one run to the next.
+#[derive(Default)]
+pub struct Answers(HashMap<(bool, usize), String>);
+
+impl Answers {
+  pub fn update(&mut self, record: &Record) -> String {
+    let result = record.result();
+    let line = record.text(true);
+
+    match self.0.insert((record.test, record.part), result.clone()) {
+      Some(previous) if previous != result => format!("{line} (was {previous})"),
+      Some(_) => format!("{line} (unchanged)"),
+      None => line,
+    }
+  }
+}
+
+// Runs the freshly built binary on the day, on the example then the real
+// input, and prints the results next to the previous ones.
+fn run_once(executable: &Path, arguments: &[String], answers: &mut Answers) {
+  for test in [true, false] {
+    let mut arguments = arguments.to_vec();
+
+    if test {
+      arguments.insert(0, "--test".to_string());
+    }
+
+    println!("{}:", if test { "Examples" } else { "Puzzle" });
+
+    match isolate::spawn_with(executable, &arguments, None, &Limits::default()) {
+      Ok(records) => {
+        for record in records {
+          println!("  {}", answers.update(&record));
+        }
+      }
+
+      Err((outcome, message)) => println!("  {outcome}: {message}"),
+    }
+  }
+}
+
+// Runs in a loop until interrupted, each run being done by a child process
+// built from the latest sources.
+pub fn run(cli: &Cli) -> ! {
+  let (Some(year), Some(day), None) = (cli.year, cli.day, &cli.input) else {
+    fail("Watching needs a year and a day, and always runs on the inputs from the data directory.");
+  };
+
+  let files = files(year, day);
+  let executable = env::current_exe().unwrap_or_else(|error| fail(format!("Could not locate the binary: {error}")));
+
+  let mut arguments = vec!["--format".to_string(), "json".to_string(), "--no-history".to_string()];
+
+  arguments.extend(["--data-dir".to_string(), util::data_dir().display().to_string()]);
+  arguments.extend(cli.overrides().iter().flat_map(|setting| ["--set".to_string(), format!("{}={}", setting.name, setting.value)]));
+  arguments.extend([year, day].iter().chain(&cli.part).map(ToString::to_string));
+
+  #[cfg(target_os = "linux")]
+  {
+    let mut watcher = Watcher::new(&files).unwrap_or_else(|error| fail(format!("Could not watch the files of the day: {error}")));
+    let mut answers = Answers::default();
+
+    println!("Watching {}", files.iter().filter(|file| file.exists()).map(|file| scaffold::display(file)).join(", "));
+
+    run_once(&executable, &arguments, &mut answers);
+
+    loop {
+      let changed = watcher.wait().unwrap_or_else(|error| fail(format!("Could not watch the files of the day: {error}")));
+
+      println!("\n{} changed, rebuilding...", changed.iter().map(|file| scaffold::display(file)).join(", "));
+
+      match rebuild() {
+        true => run_once(&executable, &arguments, &mut answers),
+        false => eprintln!("The build failed, waiting for the next change."),
+      }
+    }
+  }
+
+  #[cfg(not(target_os = "linux"))]
+  {
+    let _ = (files, executable, arguments);
+
+    fail("Watching relies on inotify, which is only available on Linux.");
+  }
+}
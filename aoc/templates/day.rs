@@ -0,0 +1,24 @@
+use crate::{answer::Answer, day::Day, error::Result, input::Input};
+
+crate::tests!(Day{{DD}}, {{YEAR}}, {{DAY}}, (0, 0));
+
+pub struct Day{{DD}};
+
+impl Day for Day{{DD}} {
+  type Input = Vec<String>;
+  type Options = ();
+
+  fn parse(input: &Input) -> Result<Self::Input> {
+    Ok(input.lines().map(|line| line.text.to_string()).collect())
+  }
+
+  #[aoc::solution(day = {{DAY}}, part = 1)]
+  fn part1(_input: &Self::Input, _opts: &Self::Options) -> Answer {
+    todo!()
+  }
+
+  #[aoc::solution(day = {{DAY}}, part = 2)]
+  fn part2(_input: &Self::Input, _opts: &Self::Options) -> Answer {
+    todo!()
+  }
+}